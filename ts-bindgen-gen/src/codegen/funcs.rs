@@ -8,8 +8,8 @@ use crate::codegen::traits::TraitMember;
 use crate::codegen::type_ref_like::{OwnedTypeRef, TypeRefLike};
 use crate::identifier::{to_snake_case_ident, Identifier};
 use crate::ir::{
-    Builtin, Class, Context, Ctor, Func, Param, TargetEnrichedTypeInfo, Tuple, TypeIdent, TypeRef,
-    Union,
+    Builtin, Class, Context, Ctor, Func, Param, TargetEnrichedTypeInfo, Tuple, TypeGuard,
+    TypeIdent, TypeOptions, TypeRef, Union,
 };
 use proc_macro2::{Literal, TokenStream as TokenStream2};
 use quote::{quote, ToTokens};
@@ -139,6 +139,7 @@ impl<'b> HasFnPrototype for OwnedTypeRef<'b> {
 struct SelfParam {
     class_name: TypeIdent,
     is_mut: bool,
+    type_options: TypeOptions,
 }
 
 /// Represents an owned parameter. Needed to render the type of the Param
@@ -163,6 +164,7 @@ impl HasFnPrototype for Func {
                 iter::once(Box::new(SelfParam {
                     class_name: class_name.clone(),
                     is_mut: false,
+                    type_options: self.context.type_options,
                 }) as Box<dyn ParamExt>)
                 .chain(reg_params),
             )
@@ -222,6 +224,7 @@ impl PropertyAccessor {
             params: Default::default(),
             return_type: Box::new(self.typ.clone()),
             class_name: Some(self.class_name.clone()),
+            type_guard: None,
             context: self.typ.context.clone(),
         }
     }
@@ -241,6 +244,7 @@ impl PropertyAccessor {
                 context: self.typ.context.clone(),
             }),
             class_name: Some(self.class_name.clone()),
+            type_guard: None,
             context: self.typ.context.clone(),
         }
     }
@@ -268,6 +272,7 @@ impl HasFnPrototype for PropertyAccessor {
         let self_param = Box::new(iter::once(Box::new(SelfParam {
             class_name: self.class_name.clone(),
             is_mut: self.access_type == AccessType::Setter,
+            type_options: self.typ.context.type_options,
         }) as Box<dyn ParamExt>)) as BoxedParamExtIter<'_>;
 
         match self.access_type {
@@ -476,7 +481,15 @@ pub mod fn_types {
         typ: &TypeRefLike,
         in_context: Option<&Context>,
     ) -> TokenStream2 {
-        exposed_to_rust_type(typ, in_context)
+        let tr = typ.as_ref();
+        match (&tr.referent, tr.type_params.first()) {
+            // readonly arrays are never mutated so we accept a slice
+            (TypeIdent::Builtin(Builtin::ReadonlyArray), Some(item_type)) => {
+                let item_type = exposed_to_rust_type(&typ.similarly_wrap(item_type), in_context);
+                quote! { &[#item_type] }
+            }
+            _ => exposed_to_rust_type(typ, in_context),
+        }
     }
 
     fn with_context<'a, C: Contextual + Clone>(
@@ -1065,7 +1078,7 @@ fn render_array_js_to_rust_conversion(
 ) -> TokenStream2 {
     let tr = typ.as_ref();
     let vec_name = name.suffix_name("_vec");
-    if let TypeIdent::Builtin(Builtin::Array) = &tr.referent {
+    if is_array(tr) {
         let inner_type = tr
             .type_params
             .first()
@@ -1189,7 +1202,14 @@ impl<T: WrappedParam> ParamExt for T {
     }
 
     fn js_to_rust_conversion(&self, _in_context: Option<&Context>) -> TokenStream2 {
-        render_js_to_rust_conversion(&self.rust_name(), &self.wrapped_type())
+        let wrapped = self.wrapped_type();
+        let conversion = render_js_to_rust_conversion(&self.rust_name(), &wrapped);
+        if wrapped.as_ref().referent == TypeIdent::Builtin(Builtin::ReadonlyArray) {
+            // matches the slice we accept for readonly array params
+            quote! { &#conversion }
+        } else {
+            conversion
+        }
     }
 
     fn local_fn_name(&self) -> Identifier {
@@ -1298,6 +1318,7 @@ impl ParamExt for SelfParam {
                 implements: Default::default(),
                 context: Context::dummy(),
             }),
+            type_options: self.type_options,
         };
         let class_name = get_name(class_name, in_context);
         quote! { &#class_name}
@@ -1436,6 +1457,62 @@ pub fn render_raw_return_to_js(return_type: &TypeRef, return_value: &TokenStream
     }
 }
 
+/// Renders an invocation of `js_fn`, an expression referencing a
+/// `js_sys::Function`, with the arguments of `f`. The rendered block
+/// evaluates to a `Result` of the rust-converted return value.
+pub fn render_js_fn_apply<F: HasFnPrototype + ?Sized>(f: &F, js_fn: &TokenStream2) -> TokenStream2 {
+    let args = quote! { _Args };
+    let return_value = quote! { ret };
+    let ret = render_raw_return_to_js(&f.return_type(), &return_value);
+    // TODO: need to render wrappers for fn params, used in rust_to_jsvalue_conversion
+    let conversions = f.args().map(|p| {
+        let name = p.rust_name();
+        let conv = p.rust_to_jsvalue_conversion(None);
+        quote! {
+            let #name = #conv;
+        }
+    });
+    let pushes = f.args().map(|p| {
+        let name = p.rust_name();
+        if p.is_variadic() {
+            quote! {
+                for #name in #name.into_iter(){
+                    #args.push(&#name);
+                }
+            }
+        } else {
+            quote! {
+                #args.push(&#name);
+            }
+        }
+    });
+
+    quote! {
+        #(#conversions);*
+        let #args = js_sys::Array::new();
+        #(#pushes);*
+        let #return_value = #js_fn.apply(&JsValue::null(), &#args)?;
+        Ok(#ret)
+    }
+}
+
+fn is_array(tr: &TypeRef) -> bool {
+    matches!(
+        tr.referent,
+        TypeIdent::Builtin(Builtin::Array | Builtin::ReadonlyArray)
+    )
+}
+
+/// Renders an iterator over the owned items of the rust array `name`. Readonly arrays
+/// are borrowed as slices so we clone their items.
+fn rust_array_items(name: &Identifier, tr: &TypeRef) -> TokenStream2 {
+    if tr.referent == TypeIdent::Builtin(Builtin::ReadonlyArray) {
+        quote! { #name.iter().cloned() }
+    } else {
+        quote! { #name.into_iter() }
+    }
+}
+
 fn render_variadic_rust_to_js_conversion(
     name: &Identifier,
     fn_name: &Identifier,
@@ -1443,7 +1520,7 @@ fn render_variadic_rust_to_js_conversion(
     error_mapper: TokenStream2,
 ) -> TokenStream2 {
     let tr = typ.as_ref();
-    if let TypeIdent::Builtin(Builtin::Array) = &tr.referent {
+    if is_array(tr) {
         let inner_type = tr
             .type_params
             .first()
@@ -1475,9 +1552,9 @@ fn render_variadic_rust_to_js_conversion(
                 .collect::<Vec<_>>()
             }
         };
+        let items = rust_array_items(name, tr);
         quote! {
-            #name
-                .into_iter()
+            #items
                 .map(|#inner_name| #inner_conversion)
                 #collect
                 .into_boxed_slice()
@@ -1551,7 +1628,7 @@ fn render_array_rust_to_js_conversion(
 ) -> TokenStream2 {
     let tr = typ.as_ref();
     let array_name = name.suffix_name("_array");
-    if let TypeIdent::Builtin(Builtin::Array) = &tr.referent {
+    if is_array(tr) {
         let inner_type = tr
             .type_params
             .first()
@@ -1572,10 +1649,11 @@ fn render_array_rust_to_js_conversion(
             error_mapper,
         )
         .1;
+        let items = rust_array_items(name, tr);
         quote! {
             {
                 let #array_name = js_sys::Array::new();
-                for #inner_name in #name {
+                for #inner_name in #items {
                     #array_name.push(&#inner_conversion);
                 }
                 #array_name.into()
@@ -1693,6 +1771,70 @@ impl<'a> WrapperFunc<'a> {
     }
 }
 
+/// For type guards, `(x: X) => x is T`, renders a companion to the rust
+/// function, `fn_name`, wrapping `func` that runs the guard and returns `x` as
+/// a `T` if it passed. `fn_target` qualifies invocations of static methods.
+pub fn render_cast_fn(
+    func: &Func,
+    fn_name: &Identifier,
+    fn_target: Option<&TokenStream2>,
+) -> Option<TokenStream2> {
+    let TypeGuard {
+        param_name,
+        asserted_type,
+    } = func.type_guard.as_ref()?;
+    let param = func
+        .params
+        .iter()
+        .find(|p| &p.name == param_name && !p.is_variadic)?;
+    if param.js_wrapper_fn(None).is_some() {
+        return None;
+    }
+
+    let cast_fn_name = fn_name.suffix_name("_and_cast");
+    let params = func
+        .params()
+        .map(|p| p.as_exposed_to_rust_named_param_list(None));
+    let name = param.rust_name();
+    let (_, value) = render_rust_to_jsvalue_conversion(
+        &name,
+        &param.local_fn_name(),
+        &param.type_ref(),
+        false,
+        quote! {
+            .map_err(ts_bindgen_rt::Error::from)
+            .map_err(JsValue::from)?
+        },
+    );
+    let asserted_type = OwnedTypeRef(Cow::Borrowed(asserted_type));
+    let check = func.invoke_with_name(fn_name);
+    let check = if func.is_member() {
+        quote! { self.#check }
+    } else if let Some(target) = fn_target {
+        quote! { #target::#check }
+    } else {
+        check
+    };
+
+    Some(quote! {
+        #[allow(dead_code, non_snake_case)]
+        pub fn #cast_fn_name(#(#params),*) -> std::result::Result<Option<#asserted_type>, JsValue> {
+            let __TSB_value = {
+                let #name = #name.clone();
+                #value
+            };
+            if !#check? {
+                return Ok(None);
+            }
+
+            ts_bindgen_rt::from_jsvalue(&__TSB_value)
+                .map(Some)
+                .map_err(ts_bindgen_rt::Error::from)
+                .map_err(JsValue::from)
+        }
+    })
+}
+
 impl<'a> ToTokens for WrapperFunc<'a> {
     fn to_tokens(&self, toks: &mut TokenStream2) {
         let fn_name = self.to_rust_name();
@@ -1706,8 +1848,10 @@ impl<'a> ToTokens for WrapperFunc<'a> {
         let our_toks = self
             .func
             .exposed_to_rust_wrapper_fn(&fn_name, &internal_fn_name, None);
+        let cast_fn = render_cast_fn(self.func, &fn_name, None);
 
         toks.extend(our_toks);
+        toks.extend(cast_fn);
     }
 }
 
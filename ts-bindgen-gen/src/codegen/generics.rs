@@ -1,13 +1,29 @@
 use crate::codegen::named::SimpleNamed;
+use crate::codegen::traits::{IsTraitable, TraitName};
 use crate::codegen::type_ref_like::{OwnedTypeRef, TypeRefLike};
 use crate::codegen::ResolveTargetType;
 use crate::identifier::to_camel_case_ident;
-use crate::ir::{Ctor, Func, Interface, Param, TargetEnrichedTypeInfo, TypeParamConfig, TypeRef};
+use crate::ir::{
+    Builtin, Ctor, Func, Interface, Param, TargetEnrichedTypeInfo, TypeGuard, TypeIdent,
+    TypeParamConfig, TypeRef,
+};
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
 use std::borrow::Cow;
 use std::collections::HashMap;
 
+/// The type env key of polymorphic `this`, which can't collide with a type
+/// param since `this` can't name one.
+pub const POLYMORPHIC_THIS_KEY: &str = "this";
+
+/// Returns the key under which `typ` is bound in a type env.
+fn type_env_key(typ: &TypeRef) -> String {
+    match &typ.referent {
+        TypeIdent::Builtin(Builtin::PolymorphicThis) => POLYMORPHIC_THIS_KEY.to_string(),
+        _ => typ.to_simple_name().to_string(),
+    }
+}
+
 pub trait ResolveGeneric: Clone {
     /// resolve this possibly-generic-referencing type into a TypeRef
     /// in the given type_env.
@@ -23,10 +39,7 @@ impl ResolveGeneric for TypeRef {
         type_env: &'a HashMap<String, TypeRef>,
     ) -> Cow<'a, Self> {
         // TODO: type_envs and type_params should key off of something better than String...
-        let mut resolved = type_env
-            .get(&self.to_simple_name().to_string())
-            .unwrap_or(self)
-            .clone();
+        let mut resolved = type_env.get(&type_env_key(self)).unwrap_or(self).clone();
         resolved.type_params = resolved
             .type_params
             .into_iter()
@@ -81,6 +94,13 @@ impl ResolveGeneric for Func {
                     .into_owned(),
             ),
             class_name: self.class_name.clone(),
+            type_guard: self.type_guard.as_ref().map(|g| TypeGuard {
+                param_name: g.param_name.clone(),
+                asserted_type: g
+                    .asserted_type
+                    .resolve_generic_in_env(type_env)
+                    .into_owned(),
+            }),
             context: self.context.clone(),
         })
     }
@@ -236,6 +256,47 @@ pub fn render_type_params_with_lifetimes(
     }
 }
 
+/// Renders type params for the definition of a type (struct, trait, or type alias),
+/// including default type arguments. Type params used in impls, fns, or type
+/// references must be rendered without defaults.
+pub fn render_type_params_with_defaults(type_params: &[(String, TypeParamConfig)]) -> TokenStream2 {
+    if type_params.is_empty() {
+        quote! {}
+    } else {
+        let type_param_toks = type_params.iter().map(|(n, cfg)| {
+            let n = to_camel_case_ident(n);
+            match &cfg.default_type_arg {
+                Some(default_type_arg) => quote! {
+                    #n = #default_type_arg
+                },
+                None => quote! {
+                    #n
+                },
+            }
+        });
+        quote! {
+            <#(#type_param_toks),*>
+        }
+    }
+}
+
+/// A typescript constraint, `T extends Base`, becomes a bound on `Base`'s trait
+/// when `Base` has a trait.
+fn constraint_bound(cfg: &TypeParamConfig) -> Option<TokenStream2> {
+    let constraint = cfg.constraint.as_ref()?;
+    if !constraint.resolve_target_type()?.is_traitable() {
+        return None;
+    }
+
+    let trait_name = constraint.trait_name();
+    if constraint.type_params.is_empty() {
+        Some(quote! { #trait_name })
+    } else {
+        let tps = &constraint.type_params;
+        Some(quote! { #trait_name<#(#tps),*> })
+    }
+}
+
 pub fn render_type_params_with_constraints(
     type_params: &[(String, TypeParamConfig)],
     extra_constraints: &[TokenStream2],
@@ -258,12 +319,16 @@ pub fn render_type_params_with_lifetimes_and_constraints(
                 let l = syn::Lifetime::new(&l, proc_macro2::Span::call_site());
                 quote! { #l }
             })
-            .chain(type_params.iter().map(|(n, _t)| {
-                let constraints = if extra_constraints.is_empty() {
+            .chain(type_params.iter().map(|(n, cfg)| {
+                let constraints: Vec<_> = constraint_bound(cfg)
+                    .into_iter()
+                    .chain(extra_constraints.iter().cloned())
+                    .collect();
+                let constraints = if constraints.is_empty() {
                     quote! {}
                 } else {
                     quote! {
-                        : #(#extra_constraints)+*
+                        : #(#constraints)+*
                     }
                 };
                 let n = to_camel_case_ident(n);
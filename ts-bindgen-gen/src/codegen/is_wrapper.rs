@@ -0,0 +1,36 @@
+use crate::codegen::resolve_target_type::ResolveTargetType;
+use crate::ir::{Interface, TargetEnrichedTypeInfo};
+
+pub trait IsWrapper {
+    /// Whether this describes a callable js object, directly or through one
+    /// of its super types.
+    fn is_callable(&self) -> bool;
+
+    /// Whether we wrap the js objects described by this rather than
+    /// (de)serializing them, which we do for callable and iterable interfaces.
+    fn is_wrapper(&self) -> bool;
+}
+
+impl IsWrapper for Interface {
+    fn is_callable(&self) -> bool {
+        self.callable.is_some() || super_interfaces(self).any(|iface| iface.is_callable())
+    }
+
+    fn is_wrapper(&self) -> bool {
+        self.callable.is_some()
+            || self.iterator.is_some()
+            || self.async_iterator.is_some()
+            || super_interfaces(self).any(|iface| iface.is_wrapper())
+    }
+}
+
+fn super_interfaces(iface: &Interface) -> impl Iterator<Item = Interface> + '_ {
+    iface
+        .extends
+        .iter()
+        .filter_map(|base| base.resolve_target_type())
+        .filter_map(|resolved_base| match resolved_base {
+            TargetEnrichedTypeInfo::Interface(iface) => Some(iface),
+            _ => None,
+        })
+}
@@ -2,6 +2,7 @@ mod contextual;
 mod funcs;
 mod generics;
 mod is_uninhabited;
+mod is_wrapper;
 mod named;
 mod ns_path;
 mod resolve_target_type;
@@ -10,18 +11,22 @@ mod traits;
 mod type_ref_like;
 
 use crate::codegen::funcs::{
-    fn_types, render_exposed_to_js_wrapper_closure, render_raw_return_to_js, AccessType,
+    fn_types, render_cast_fn, render_exposed_to_js_wrapper_closure, render_js_fn_apply, AccessType,
     Constructor, FnPrototypeExt, HasFnPrototype, InternalFunc, PropertyAccessor, WrapperFunc,
 };
 use crate::codegen::generics::{
     apply_type_params, render_type_params, render_type_params_with_constraints,
-    render_type_params_with_lifetimes, ResolveGeneric, TypeEnvImplying,
+    render_type_params_with_defaults, render_type_params_with_lifetimes, ResolveGeneric,
+    TypeEnvImplying,
 };
 use crate::codegen::is_uninhabited::IsUninhabited;
+use crate::codegen::is_wrapper::IsWrapper;
 use crate::codegen::named::{CasedTypeIdent, FnOverloadName, Named, SimpleNamed, UnionCaseName};
 use crate::codegen::ns_path::ToNsPath;
 use crate::codegen::resolve_target_type::ResolveTargetType;
-use crate::codegen::serialization_type::{SerializationType, SerializationTypeGetter};
+use crate::codegen::serialization_type::{
+    bigint_serialize_with, SerializationType, SerializationTypeGetter,
+};
 use crate::codegen::traits::{render_trait_defn, to_type_ref, IsTraitable, TraitName, Traitable};
 use crate::codegen::type_ref_like::OwnedTypeRef;
 use crate::fs::Fs;
@@ -30,10 +35,13 @@ use crate::identifier::{
 };
 use crate::ir::{
     Alias, Builtin, Class, Context, Ctor, Enum, EnumMember, EnumValue, Func, Indexer, Interface,
-    Intersection, Member, NamespaceImport, Param, TargetEnrichedType, TargetEnrichedTypeInfo,
-    Tuple, TypeIdent, TypeParamConfig, TypeRef, Union,
+    Intersection, JsImport, Member, MemberKey, NamespaceImport, Param, TargetEnrichedType,
+    TargetEnrichedTypeInfo, TemplateHole, TemplateLiteral, Tuple, TypeIdent, TypeParamConfig,
+    TypeRef, Union,
 };
 pub use crate::mod_def::ModDef;
+use crate::mod_def::ToModPathIter;
+use heck::CamelCase;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 use std::borrow::Cow;
@@ -163,24 +171,6 @@ impl<'a, FS: Fs + ?Sized> ToTokens for WithFs<'a, ModDef, FS> {
     }
 }
 
-impl ToTokens for EnumMember {
-    fn to_tokens(&self, toks: &mut TokenStream2) {
-        let id = to_camel_case_ident(&self.id);
-        let our_toks = {
-            if let Some(value) = &self.value {
-                quote! {
-                    #id = #value
-                }
-            } else {
-                quote! {
-                    #id
-                }
-            }
-        };
-        toks.append_all(our_toks);
-    }
-}
-
 fn get_recursive_fields(iface: &Interface) -> HashMap<String, TypeRef> {
     get_recursive_fields_with_type_params(iface, &Default::default())
 }
@@ -213,6 +203,64 @@ fn get_recursive_fields_with_type_params(
     our_fields.chain(super_fields).collect()
 }
 
+/// Returns the call signatures of `iface` and its super interfaces, resolved
+/// in `type_env`.
+fn get_recursive_call_overloads(
+    Interface {
+        extends, callable, ..
+    }: &Interface,
+    type_env: &HashMap<String, TypeRef>,
+) -> Vec<Func> {
+    let our_overloads = callable
+        .iter()
+        .flat_map(|c| c.overloads.iter())
+        .map(|func| func.resolve_generic_in_env(type_env).into_owned());
+    // polymorphic `this` in a super interface still refers to our type
+    let this_env = type_env.get_key_value(POLYMORPHIC_THIS_KEY);
+    let super_overloads = extends
+        .iter()
+        .filter_map(|base| base.resolve_target_type().map(|t| (base, t)))
+        .filter_map(|(base, resolved_base)| match resolved_base {
+            TargetEnrichedTypeInfo::Interface(iface) => Some((base, iface)),
+            _ => None,
+        })
+        .flat_map(|(base, iface)| {
+            let super_type_env = apply_type_params(base, &iface, type_env)
+                .into_iter()
+                .chain(this_env.map(|(k, v)| (k.clone(), v.clone())))
+                .collect();
+            get_recursive_call_overloads(&iface, &super_type_env)
+        });
+
+    our_overloads.chain(super_overloads).collect()
+}
+
+fn get_recursive_readonly_fields(
+    Interface {
+        extends,
+        fields,
+        readonly_fields,
+        ..
+    }: &Interface,
+) -> HashSet<String> {
+    // a field redeclared (without readonly) by a sub-interface is writable
+    let super_readonly_fields = extends
+        .iter()
+        .filter_map(|base| base.resolve_target_type())
+        .filter_map(|resolved_base| match resolved_base {
+            TargetEnrichedTypeInfo::Interface(iface) => Some(iface),
+            _ => None,
+        })
+        .flat_map(|iface| get_recursive_readonly_fields(&iface).into_iter())
+        .filter(|n| !fields.contains_key(n));
+
+    readonly_fields
+        .iter()
+        .cloned()
+        .chain(super_readonly_fields)
+        .collect()
+}
+
 fn path_relative_to_cargo_toml<T: AsRef<Path>>(path: T) -> PathBuf {
     let mut best: Option<PathBuf> = None;
     let mut current_path: Option<PathBuf> = None;
@@ -300,6 +348,7 @@ impl FieldCountGetter for TargetEnrichedTypeInfo {
                         .unwrap_or(0)
             },
             TargetEnrichedTypeInfo::Var { .. } => min,
+            TargetEnrichedTypeInfo::TemplateLiteral(_) => min,
         )
     }
 }
@@ -329,6 +378,10 @@ impl MemberContainer for Union {
 
 trait JsModulePath {
     fn js_module_path(&self) -> String;
+
+    /// Renders the wasm_bindgen attribute for extern blocks importing from
+    /// our js module or, for globals, our js namespace.
+    fn js_import_attr(&self) -> TokenStream2;
 }
 
 impl JsModulePath for Context {
@@ -339,12 +392,417 @@ impl JsModulePath for Context {
         ));
         path.to_string_lossy().to_string()
     }
+
+    fn js_import_attr(&self) -> TokenStream2 {
+        match &self.js_import {
+            JsImport::Module => {
+                let path = self.js_module_path();
+                quote! { #[wasm_bindgen(module = #path)] }
+            }
+            JsImport::Global { js_namespace } if js_namespace.is_empty() => {
+                quote! { #[wasm_bindgen] }
+            }
+            JsImport::Global { js_namespace } if js_namespace.len() == 1 => {
+                let js_namespace = format_ident!("{}", js_namespace[0]);
+                quote! { #[wasm_bindgen(js_namespace = #js_namespace)] }
+            }
+            JsImport::Global { js_namespace } => {
+                quote! { #[wasm_bindgen(js_namespace = [#(#js_namespace),*])] }
+            }
+        }
+    }
 }
 
 fn to_internal_class_name(name: &Identifier) -> Identifier {
     name.suffix_name("_Class")
 }
 
+/// Renders the wrapper struct for a js object of type `internal_name` along
+/// with the conversions needed to pass it to and from js. `instanceof`
+/// determines whether `val` is an instance of the wrapped type.
+fn render_wrapper_type(
+    vis: &TokenStream2,
+    name: &Identifier,
+    internal_name: &Identifier,
+    type_params: &[(String, TypeParamConfig)],
+    instanceof: TokenStream2,
+) -> TokenStream2 {
+    let full_type_params = render_type_params(type_params);
+    let type_params_with_defaults = render_type_params_with_defaults(type_params);
+    let wrapper_struct_members = if type_params.is_empty() {
+        vec![quote! { pub #internal_name }]
+    } else {
+        vec![
+            quote! { pub #internal_name },
+            quote! { pub std::marker::PhantomData #full_type_params },
+        ]
+    };
+    let extra_args = if type_params.is_empty() {
+        Default::default()
+    } else {
+        quote! { std::marker::PhantomData }
+    };
+    let wrapper_from_internal_args = vec![quote! { internal }, extra_args.clone()];
+    let type_params_with_de_lifetime = render_type_params_with_lifetimes(type_params, &["de"]);
+    let type_params_with_a_lifetime = render_type_params_with_lifetimes(type_params, &["a"]);
+
+    quote! {
+        #[derive(std::clone::Clone)]
+        #vis struct #name #type_params_with_defaults(#(#wrapper_struct_members),*);
+
+        impl #full_type_params std::convert::From<#name #full_type_params> for JsValue {
+            fn from(src: #name #full_type_params) -> JsValue {
+                JsValue::from(src.0)
+            }
+        }
+
+        impl #full_type_params std::convert::AsRef<JsValue> for #name #full_type_params {
+            fn as_ref(&self) -> &JsValue {
+                self.0.as_ref()
+            }
+        }
+
+        impl #full_type_params wasm_bindgen::JsCast for #name #full_type_params {
+            fn instanceof(val: &JsValue) -> bool {
+                #instanceof
+            }
+            fn unchecked_from_js(val: JsValue) -> Self {
+                #name(#internal_name::unchecked_from_js(val), #extra_args)
+            }
+            fn unchecked_from_js_ref(val: &JsValue) -> &Self {
+                unsafe {
+                    &*(#internal_name::unchecked_from_js_ref(val) as *const #internal_name as *const Self)
+                }
+            }
+        }
+
+        impl #full_type_params wasm_bindgen::describe::WasmDescribe for #name #full_type_params {
+            fn describe() {
+                <#internal_name as wasm_bindgen::describe::WasmDescribe>::describe()
+            }
+        }
+
+        impl #full_type_params wasm_bindgen::convert::IntoWasmAbi for #name #full_type_params {
+            type Abi = <#internal_name as wasm_bindgen::convert::IntoWasmAbi>::Abi;
+            fn into_abi(self) -> Self::Abi {
+                wasm_bindgen::convert::IntoWasmAbi::into_abi(self.0)
+            }
+        }
+
+        impl #full_type_params wasm_bindgen::convert::FromWasmAbi for #name #full_type_params {
+            type Abi = <#internal_name as wasm_bindgen::convert::FromWasmAbi>::Abi;
+            unsafe fn from_abi(js: Self::Abi) -> Self {
+                #name(wasm_bindgen::convert::FromWasmAbi::from_abi(js), #extra_args)
+            }
+        }
+
+        impl #type_params_with_a_lifetime wasm_bindgen::convert::IntoWasmAbi for &'a #name #full_type_params {
+            type Abi = <&'a #internal_name as wasm_bindgen::convert::IntoWasmAbi>::Abi;
+            fn into_abi(self) -> Self::Abi {
+                wasm_bindgen::convert::IntoWasmAbi::into_abi(&self.0)
+            }
+        }
+
+        impl #full_type_params serde::ser::Serialize for #name #full_type_params {
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: serde::ser::Serializer,
+            {
+                serde::ser::Serialize::serialize(&self.0, serializer)
+            }
+        }
+
+        impl #type_params_with_de_lifetime serde::de::Deserialize<'de> for #name #full_type_params {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: serde::de::Deserializer<'de>,
+            {
+                let internal: #internal_name = <#internal_name as serde::de::Deserialize>::deserialize(deserializer)?;
+                std::result::Result::Ok(Self(#(#wrapper_from_internal_args),*))
+            }
+        }
+
+        impl std::clone::Clone for #internal_name {
+            fn clone(&self) -> Self {
+                Self { obj: std::clone::Clone::clone(&self.obj) }
+            }
+        }
+
+        impl serde::ser::Serialize for #internal_name {
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: serde::ser::Serializer,
+            {
+                ts_bindgen_rt::serialize_as_jsvalue(serializer, self)
+            }
+        }
+
+        impl<'de> serde::de::Deserialize<'de> for #internal_name {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: serde::de::Deserializer<'de>,
+            {
+                ts_bindgen_rt::deserialize_as_jsvalue(deserializer)
+            }
+        }
+    }
+}
+
+/// Returns the type environments used to render the members of a js wrapper
+/// type referenced by `self_ref`. The first erases type params, for use in
+/// extern declarations, and the second maps polymorphic `this` to our wrapper
+/// type.
+fn member_type_envs(
+    self_ref: &TypeRef,
+    type_params: &[(String, TypeParamConfig)],
+    context: &Context,
+) -> (HashMap<String, TypeRef>, HashMap<String, TypeRef>) {
+    let type_env: HashMap<_, _> = type_params
+        .iter()
+        .map(|(n, _)| {
+            (
+                n.clone(),
+                TypeRef {
+                    referent: TypeIdent::Builtin(Builtin::PrimitiveAny),
+                    type_params: Default::default(),
+                    context: context.clone(),
+                },
+            )
+        })
+        .collect();
+    let this_env: HashMap<_, _> = iter::once((
+        POLYMORPHIC_THIS_KEY.to_string(),
+        self_ref.resolve_generic_in_env(&type_env).into_owned(),
+    ))
+    .collect();
+    let type_env = type_env.into_iter().chain(this_env.clone()).collect();
+
+    (type_env, this_env)
+}
+
+/// Renders the getter and setter for the `member_js_name` property of a js
+/// object of type `internal_name`, returning a tuple of the extern
+/// declarations and the public functions exposing them on `wrapper_name`.
+/// Readonly properties only get a getter.
+#[allow(clippy::too_many_arguments)]
+fn render_property_accessors(
+    member_js_name: &str,
+    typ: &TypeRef,
+    readonly: bool,
+    wrapper_name: &TypeIdent,
+    internal_name: &Identifier,
+    js_class: Option<&str>,
+    type_env: &HashMap<String, TypeRef>,
+    this_env: &HashMap<String, TypeRef>,
+) -> (TokenStream2, TokenStream2) {
+    let member_js_ident = format_ident!("{}", member_js_name);
+    let js_class = js_class
+        .map(|js_class| quote! { , js_class = #js_class })
+        .unwrap_or_default();
+    let target = quote! { self.0 };
+    let resolved_type = typ.resolve_generic_in_env(type_env).into_owned();
+    let member_name = to_snake_case_ident(member_js_name);
+    let setter_name = member_name.prefix_name("set_");
+
+    let (internal_getter_name, internal_setter_name) =
+        if resolved_type.serialization_type() == SerializationType::Raw {
+            (member_name.clone(), setter_name.clone())
+        } else {
+            (
+                InternalFunc::to_internal_rust_ident(&member_name),
+                InternalFunc::to_internal_rust_ident(&setter_name),
+            )
+        };
+
+    let internal_getter = PropertyAccessor {
+        property_name: member_name.clone(),
+        typ: resolved_type.clone(),
+        class_name: TypeIdent::ExactName(internal_name.to_string()),
+        access_type: AccessType::Getter,
+    };
+
+    let internal_setter = PropertyAccessor {
+        property_name: member_name.clone(),
+        typ: resolved_type,
+        class_name: TypeIdent::ExactName(internal_name.to_string()),
+        access_type: AccessType::Setter,
+    };
+
+    let member_getter = internal_getter.exposed_to_js_fn_decl(&internal_getter_name, None);
+    let member_setter = if readonly {
+        quote! {}
+    } else {
+        let member_setter = internal_setter.exposed_to_js_fn_decl(&internal_setter_name, None);
+        quote! {
+            #[wasm_bindgen(method, structural, catch, setter = #member_js_ident #js_class)]
+            #member_setter;
+        }
+    };
+    let member_def = quote! {
+        #[wasm_bindgen(method, structural, catch, getter = #member_js_ident #js_class)]
+        #member_getter;
+
+        #member_setter
+    };
+
+    let typ = typ.resolve_generic_in_env(this_env);
+    let getter = PropertyAccessor {
+        property_name: member_name.clone(),
+        typ: typ.clone().into_owned(),
+        class_name: wrapper_name.clone(),
+        access_type: AccessType::Getter,
+    }
+    .getter_fn();
+
+    let setter = PropertyAccessor {
+        property_name: member_name,
+        typ: typ.into_owned(),
+        class_name: wrapper_name.clone(),
+        access_type: AccessType::Setter,
+    }
+    .setter_fn();
+
+    let rc: Option<&fn(TokenStream2) -> TokenStream2> = None;
+    let getter_fn = getter.exposed_to_rust_generic_wrapper_fn(
+        &to_snake_case_ident(member_js_name),
+        Some(&target),
+        &internal_getter_name,
+        true,
+        rc,
+        type_env,
+        None,
+    );
+    let setter_fn = if readonly {
+        quote! {}
+    } else {
+        setter.exposed_to_rust_generic_wrapper_fn(
+            &setter_name,
+            Some(&target),
+            &internal_setter_name,
+            true,
+            rc,
+            type_env,
+            None,
+        )
+    };
+
+    let pub_fn = quote! {
+        #getter_fn
+
+        #setter_fn
+    };
+
+    (member_def, pub_fn)
+}
+
+/// Returns the name we expose the static class member named `js_name` by in
+/// rust, which is prefixed with `static_` if the class also has an instance
+/// member of the same name.
+fn static_member_rust_name(members: &HashMap<MemberKey, Member>, js_name: &str) -> String {
+    if members.contains_key(&MemberKey::Instance(js_name.to_string())) {
+        format!("static_{}", js_name)
+    } else {
+        js_name.to_string()
+    }
+}
+
+/// Renders the getter and setter for the `member_js_name` static property of
+/// the js class `js_class`, exposed to rust as `member_rust_name`. Like
+/// [`render_property_accessors`], this returns the extern declarations along
+/// with the public functions wrapping them.
+#[allow(clippy::too_many_arguments)]
+fn render_static_property_accessors(
+    member_js_name: &str,
+    member_rust_name: &str,
+    typ: &TypeRef,
+    readonly: bool,
+    internal_name: &Identifier,
+    js_class: &str,
+    type_env: &HashMap<String, TypeRef>,
+    this_env: &HashMap<String, TypeRef>,
+) -> (TokenStream2, TokenStream2) {
+    let member_js_ident = format_ident!("{}", member_js_name);
+    let resolved_type = typ.resolve_generic_in_env(type_env).into_owned();
+    let member_name = to_snake_case_ident(member_rust_name);
+    let setter_name = member_name.prefix_name("set_");
+
+    let (internal_getter_name, internal_setter_name) =
+        if resolved_type.serialization_type() == SerializationType::Raw {
+            (member_name.clone(), setter_name.clone())
+        } else {
+            (
+                InternalFunc::to_internal_rust_ident(&member_name),
+                InternalFunc::to_internal_rust_ident(&setter_name),
+            )
+        };
+
+    // static accessors are plain functions without a receiver
+    let accessor_fns = |typ: TypeRef| {
+        let accessor = PropertyAccessor {
+            property_name: member_name.clone(),
+            typ,
+            class_name: TypeIdent::ExactName(internal_name.to_string()),
+            access_type: AccessType::Getter,
+        };
+        let mut getter = accessor.getter_fn();
+        getter.class_name = None;
+        let mut setter = accessor.setter_fn();
+        setter.class_name = None;
+        (getter, setter)
+    };
+
+    let (internal_getter, internal_setter) = accessor_fns(resolved_type);
+    let member_getter = internal_getter.exposed_to_js_fn_decl(&internal_getter_name, None);
+    let member_setter = if readonly {
+        quote! {}
+    } else {
+        let member_setter = internal_setter.exposed_to_js_fn_decl(&internal_setter_name, None);
+        quote! {
+            #[wasm_bindgen(static_method_of = #internal_name, js_class = #js_class, catch, setter = #member_js_ident)]
+            #member_setter;
+        }
+    };
+    let member_def = quote! {
+        #[wasm_bindgen(static_method_of = #internal_name, js_class = #js_class, catch, getter = #member_js_ident)]
+        #member_getter;
+
+        #member_setter
+    };
+
+    let (getter, setter) = accessor_fns(typ.resolve_generic_in_env(this_env).into_owned());
+    let rc: Option<&fn(TokenStream2) -> TokenStream2> = None;
+    let getter_fn = getter.exposed_to_rust_generic_wrapper_fn(
+        &member_name,
+        None,
+        &internal_getter_name.in_namespace(internal_name),
+        true,
+        rc,
+        type_env,
+        None,
+    );
+    let setter_fn = if readonly {
+        quote! {}
+    } else {
+        setter.exposed_to_rust_generic_wrapper_fn(
+            &setter_name,
+            None,
+            &internal_setter_name.in_namespace(internal_name),
+            true,
+            rc,
+            type_env,
+            None,
+        )
+    };
+
+    let pub_fn = quote! {
+        #getter_fn
+
+        #setter_fn
+    };
+
+    (member_def, pub_fn)
+}
+
 impl<'a, FS: Fs + ?Sized> ToTokens for WithFs<'a, TargetEnrichedType, FS> {
     fn to_tokens(&self, toks: &mut TokenStream2) {
         let WithFs { data: typ, fs } = self;
@@ -353,6 +811,7 @@ impl<'a, FS: Fs + ?Sized> ToTokens for WithFs<'a, TargetEnrichedType, FS> {
         let cased_type_name = CasedTypeIdent {
             type_ident: type_name,
             type_info: &typ.info,
+            type_options: typ.context.type_options,
         };
         let (js_name, name) = cased_type_name.to_name();
         let vis = if is_exported {
@@ -363,6 +822,121 @@ impl<'a, FS: Fs + ?Sized> ToTokens for WithFs<'a, TargetEnrichedType, FS> {
         };
 
         let our_toks = match &typ.info {
+            TargetEnrichedTypeInfo::Interface(
+                iface @ Interface {
+                    iterator,
+                    async_iterator,
+                    type_params,
+                    context,
+                    ..
+                },
+            ) if iface.is_wrapper() => {
+                // callable interfaces describe js functions with properties and
+                // iterable interfaces describe js objects we can only iterate
+                // through js so we wrap them rather than (de)serializing a struct
+                let internal_name = to_internal_class_name(&name);
+                let full_type_params = render_type_params(type_params);
+                let full_type_params_deserializable = render_type_params_with_constraints(
+                    type_params,
+                    &[
+                        quote! { serde::ser::Serialize },
+                        quote! { serde::de::DeserializeOwned },
+                    ],
+                );
+                let iface_ref = to_type_ref(type_name, type_params, context);
+                let (type_env, this_env) = member_type_envs(&iface_ref, type_params, context);
+                let readonly_fields = get_recursive_readonly_fields(iface);
+                let (member_defs, property_fns): (Vec<TokenStream2>, Vec<TokenStream2>) =
+                    get_recursive_fields(iface)
+                        .iter()
+                        .map(|(js_field_name, typ)| {
+                            render_property_accessors(
+                                js_field_name,
+                                typ,
+                                readonly_fields.contains(js_field_name),
+                                type_name,
+                                &internal_name,
+                                None,
+                                &type_env,
+                                &this_env,
+                            )
+                        })
+                        .unzip();
+                let field_names = get_recursive_fields(iface).into_keys().collect::<Vec<_>>();
+                let call_overloads = get_recursive_call_overloads(iface, &type_env);
+                let is_overloaded = call_overloads.len() > 1;
+                let fn_group_name = unique_member_fn_name("call", field_names.iter());
+                let call_fns = call_overloads.into_iter().map(|mut func| {
+                    func.class_name = Some(type_name.clone());
+                    let fn_name = if is_overloaded {
+                        func.overload_name(&fn_group_name)
+                    } else {
+                        fn_group_name.clone()
+                    };
+                    let f = func.exposed_to_rust_fn_decl(&fn_name, true, None);
+                    let wrapper_fns = func.exposed_to_rust_param_wrappers(None);
+                    let invocation = render_js_fn_apply(&func, &quote! { js_fn });
+                    let cast_fn = render_cast_fn(&func, &fn_name, None);
+
+                    quote! {
+                        #[allow(dead_code, non_snake_case)]
+                        pub #f {
+                            #wrapper_fns
+
+                            let js_fn: &js_sys::Function = self.0.as_ref();
+                            #invocation
+                        }
+
+                        #cast_fn
+                    }
+                });
+                let iter_fns = iterator
+                    .iter()
+                    .map(|item_type| {
+                        render_iter_fn(
+                            &unique_member_fn_name("iter", field_names.iter()),
+                            &item_type.resolve_generic_in_env(&this_env),
+                        )
+                    })
+                    .chain(async_iterator.iter().map(|item_type| {
+                        render_stream_fn(
+                            &unique_member_fn_name("stream", field_names.iter()),
+                            &item_type.resolve_generic_in_env(&this_env),
+                        )
+                    }));
+                let (extends, instanceof) = if iface.is_callable() {
+                    (quote! { js_sys::Function }, quote! { val.is_function() })
+                } else {
+                    (quote! { js_sys::Object }, quote! { val.is_object() })
+                };
+                let wrapper_type =
+                    render_wrapper_type(&vis, &name, &internal_name, type_params, instanceof);
+                let trait_defn =
+                    render_trait_defn(&name, type_name, type_params, is_exported, iface, context);
+
+                quote! {
+                    #[wasm_bindgen]
+                    extern "C" {
+                        #[allow(non_camel_case_types)]
+                        #[wasm_bindgen(extends = #extends)]
+                        #vis type #internal_name;
+
+                        #(#member_defs)*
+                    }
+
+                    #wrapper_type
+
+                    impl #full_type_params_deserializable #name #full_type_params {
+                        #(#call_fns)*
+
+                        #(#property_fns)*
+
+                        #(#iter_fns)*
+                    }
+
+                    #trait_defn
+                }
+            }
             TargetEnrichedTypeInfo::Interface(iface) => {
                 let Interface {
                     indexer,
@@ -372,7 +946,7 @@ impl<'a, FS: Fs + ?Sized> ToTokens for WithFs<'a, TargetEnrichedType, FS> {
                 } = iface;
                 let extended_fields = get_recursive_fields(iface);
 
-                let full_type_params = render_type_params(type_params);
+                let type_params_with_defaults = render_type_params_with_defaults(type_params);
                 let mut field_toks = extended_fields
                     .iter()
                     .map(|(js_field_name, typ)| {
@@ -439,7 +1013,7 @@ impl<'a, FS: Fs + ?Sized> ToTokens for WithFs<'a, TargetEnrichedType, FS> {
 
                 quote! {
                     #[derive(Clone, serde::Serialize, serde::Deserialize)]
-                    pub struct #name #full_type_params {
+                    pub struct #name #type_params_with_defaults {
                         #(#field_toks),*
                     }
 
@@ -448,55 +1022,173 @@ impl<'a, FS: Fs + ?Sized> ToTokens for WithFs<'a, TargetEnrichedType, FS> {
                     #serializer_impl
                 }
             }
-            TargetEnrichedTypeInfo::Enum(Enum { members, .. }) => {
+            TargetEnrichedTypeInfo::Enum(Enum { members, .. })
+                if members
+                    .iter()
+                    .all(|m| matches!(m.value, Some(EnumValue::Str(_)))) =>
+            {
+                // string enums (de)serialize as their values
+                let ids = enum_member_idents(members);
+                let variants = ids.iter().zip(members.iter()).map(|(id, m)| {
+                    let value = &m.value;
+                    quote! {
+                        #[serde(rename = #value)]
+                        #id
+                    }
+                });
+
+                let str_conversions = render_enum_str_conversions(&name, &ids, members);
+
                 quote! {
-                    #[wasm_bindgen]
-                    #[derive(Clone, serde::Serialize, serde::Deserialize)]
-                    #[serde(untagged)]
+                    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
                     pub enum #name {
-                        #(#members),*
+                        #(#variants),*
                     }
+
+                    #str_conversions
                 }
             }
-            TargetEnrichedTypeInfo::Alias(Alias {
-                target,
-                type_params,
-                ..
-            }) => {
-                let tps = render_type_params(type_params);
-
-                let is_class = target
-                    .resolve_target_type()
-                    .map(|t| matches!(t, TargetEnrichedTypeInfo::Class(_)))
-                    .unwrap_or(false);
-
-                // would like to alias traits here if
-                // target.resolve_target_type().map(IsTraitable::is_traitable)
-                // but trait aliases are useless.
-
-                let class_alias = if is_class {
-                    let target_class_name = to_internal_class_name(&target.to_name().1);
-                    let class_name = to_internal_class_name(&name);
-                    quote! {
-                        #[allow(dead_code, non_camel_case_types)]
-                        #vis type #class_name = #target_class_name;
-                    }
+            TargetEnrichedTypeInfo::Enum(Enum { members, .. }) => {
+                // numeric (or mixed) enums (de)serialize as their values.
+                // we can only use the values as discriminants if they're
+                // distinct 32-bit integers.
+                let discriminants: Option<Vec<i32>> = members
+                    .iter()
+                    .map(|m| match m.value {
+                        Some(EnumValue::Num(n))
+                            if n.fract() == 0.0
+                                && n >= f64::from(i32::MIN)
+                                && n <= f64::from(i32::MAX) =>
+                        {
+                            Some(n as i32)
+                        }
+                        _ => None,
+                    })
+                    .collect::<Option<_>>()
+                    .filter(|d: &Vec<i32>| d.iter().collect::<HashSet<_>>().len() == d.len());
+                let repr = if discriminants.is_some() {
+                    quote! { #[repr(i32)] }
                 } else {
                     quote! {}
                 };
-
-                if matches!(type_name, TypeIdent::DefaultExport(_)) {
-                    // we don't emit default export aliases. we look them up
-                    // when referenced.
-                    quote! {}
-                } else {
+                let ids = enum_member_idents(members);
+                let variants = ids.iter().enumerate().map(|(i, id)| {
+                    match discriminants.as_ref().map(|d| d[i]) {
+                        Some(d) => {
+                            let d = proc_macro2::Literal::i32_unsuffixed(d);
+                            quote! { #id = #d }
+                        }
+                        None => quote! { #id },
+                    }
+                });
+                let serialize_cases = ids.iter().zip(members.iter()).map(|(id, m)| {
+                    let serialized = match &m.value {
+                        Some(EnumValue::Str(s)) => quote! { serializer.serialize_str(#s) },
+                        Some(EnumValue::Num(n)) => quote! { serializer.serialize_f64(#n) },
+                        Some(EnumValue::Bool(b)) => quote! { serializer.serialize_bool(#b) },
+                        None => quote! { serializer.serialize_unit() },
+                    };
                     quote! {
-                        #[allow(dead_code, non_camel_case_types)]
-                        #vis type #name #tps = #target;
-
-                        #class_alias
+                        #name::#id => #serialized
                     }
-                }
+                });
+                let deserialize_cases = ids.iter().zip(members.iter()).filter_map(|(id, m)| {
+                    let guard = match &m.value {
+                        Some(EnumValue::Str(s)) => quote! { EnumValue::Str(s) if s == #s },
+                        Some(EnumValue::Num(n)) => quote! { EnumValue::Num(n) if n == #n },
+                        Some(EnumValue::Bool(b)) => quote! { EnumValue::Bool(b) if b == #b },
+                        None => return None,
+                    };
+                    Some(quote! {
+                        #guard => Ok(#name::#id)
+                    })
+                });
+
+                let str_conversions = render_enum_str_conversions(&name, &ids, members);
+
+                quote! {
+                    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+                    #repr
+                    pub enum #name {
+                        #(#variants),*
+                    }
+
+                    impl serde::ser::Serialize for #name {
+                        fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+                        where
+                            S: serde::ser::Serializer,
+                        {
+                            match self {
+                                #(#serialize_cases),*
+                            }
+                        }
+                    }
+
+                    impl<'de> serde::de::Deserialize<'de> for #name {
+                        fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+                        where
+                            D: serde::de::Deserializer<'de>,
+                        {
+                            #[derive(serde::Deserialize)]
+                            #[serde(untagged)]
+                            enum EnumValue {
+                                Num(f64),
+                                Str(String),
+                                Bool(bool),
+                            }
+
+                            match <EnumValue as serde::de::Deserialize>::deserialize(deserializer)? {
+                                #(#deserialize_cases,)*
+                                _ => Err(serde::de::Error::custom(concat!("unexpected value for enum ", #js_name))),
+                            }
+                        }
+                    }
+
+                    #str_conversions
+                }
+            }
+            TargetEnrichedTypeInfo::TemplateLiteral(template) => {
+                render_template_literal(&name, template)
+            }
+            TargetEnrichedTypeInfo::Alias(Alias {
+                target,
+                type_params,
+                ..
+            }) => {
+                let tps = render_type_params_with_defaults(type_params);
+
+                let is_class = target
+                    .resolve_target_type()
+                    .map(|t| matches!(t, TargetEnrichedTypeInfo::Class(_)))
+                    .unwrap_or(false);
+
+                // would like to alias traits here if
+                // target.resolve_target_type().map(IsTraitable::is_traitable)
+                // but trait aliases are useless.
+
+                let class_alias = if is_class {
+                    let target_class_name = to_internal_class_name(&target.to_name().1);
+                    let class_name = to_internal_class_name(&name);
+                    quote! {
+                        #[allow(dead_code, non_camel_case_types)]
+                        #vis type #class_name = #target_class_name;
+                    }
+                } else {
+                    quote! {}
+                };
+
+                if matches!(type_name, TypeIdent::DefaultExport(_)) {
+                    // we don't emit default export aliases. we look them up
+                    // when referenced.
+                    quote! {}
+                } else {
+                    quote! {
+                        #[allow(dead_code, non_camel_case_types)]
+                        #vis type #name #tps = #target;
+
+                        #class_alias
+                    }
+                }
             }
             TargetEnrichedTypeInfo::Union(u) => {
                 let (undefined_members, mut not_undefined_members) =
@@ -530,7 +1222,12 @@ impl<'a, FS: Fs + ?Sized> ToTokens for WithFs<'a, TargetEnrichedType, FS> {
                                     ]
                                 }
                             },
-                            _ => Default::default(),
+                            _ => match bigint_serialize_with(t) {
+                                Some(serialize_fn) => quote! {
+                                    #[serde(serialize_with = #serialize_fn)]
+                                },
+                                None => Default::default(),
+                            },
                         };
 
                         if t.is_uninhabited() {
@@ -611,14 +1308,9 @@ impl<'a, FS: Fs + ?Sized> ToTokens for WithFs<'a, TargetEnrichedType, FS> {
                     #impls
                 }
             }
-            TargetEnrichedTypeInfo::Tuple(Tuple { types, .. }) => {
-                quote! {
-                    #[derive(Clone, serde::Serialize, serde::Deserialize)]
-                    pub struct #name(#(pub #types),*);
-                }
-            }
+            TargetEnrichedTypeInfo::Tuple(tuple) => render_tuple(&name, js_name, tuple),
             TargetEnrichedTypeInfo::FuncGroup(func_group) => {
-                let path = func_group.context.js_module_path();
+                let import_attr = func_group.context.js_import_attr();
                 let common_attrs = vec![quote! { js_name = #js_name, catch }];
                 let is_overloaded = func_group.overloads.len() > 1;
                 let funcs = func_group.overloads.iter().map(|func| {
@@ -641,7 +1333,7 @@ impl<'a, FS: Fs + ?Sized> ToTokens for WithFs<'a, TargetEnrichedType, FS> {
                     };
 
                     quote! {
-                        #[wasm_bindgen(module=#path)]
+                        #import_attr
                         extern "C" {
                             #[allow(non_snake_case)]
                             #[wasm_bindgen(#(#attrs),*)]
@@ -656,6 +1348,44 @@ impl<'a, FS: Fs + ?Sized> ToTokens for WithFs<'a, TargetEnrichedType, FS> {
                     #(#funcs)*
                 }
             }
+            TargetEnrichedTypeInfo::Var { type_info } => {
+                // we import variables as js values and expose a getter that
+                // converts them to their rust type
+                let import_attr = typ.context.js_import_attr();
+                let internal_name = InternalFunc::to_internal_rust_ident(&name);
+                let unchecked_into = quote! {
+                    Ok(wasm_bindgen::JsCast::unchecked_into(value))
+                };
+                let (var_type, conversion) = match type_info.as_ref() {
+                    TargetEnrichedTypeInfo::Ref(tr) => match tr.serialization_type() {
+                        SerializationType::JsValue => (quote! { #tr }, unchecked_into),
+                        SerializationType::Fn => (quote! { js_sys::Function }, unchecked_into),
+                        _ => (
+                            quote! { #tr },
+                            quote! {
+                                ts_bindgen_rt::from_jsvalue(&value)
+                                    .map_err(ts_bindgen_rt::Error::from)
+                                    .map_err(JsValue::from)
+                            },
+                        ),
+                    },
+                    _ => (quote! { JsValue }, quote! { Ok(value) }),
+                };
+
+                quote! {
+                    #import_attr
+                    extern "C" {
+                        #[allow(non_upper_case_globals)]
+                        #[wasm_bindgen(js_name = #js_name)]
+                        static #internal_name: JsValue;
+                    }
+
+                    pub fn #name() -> std::result::Result<#var_type, JsValue> {
+                        let value = JsValue::clone(&#internal_name);
+                        #conversion
+                    }
+                }
+            }
             TargetEnrichedTypeInfo::Class(class) => {
                 let Class {
                     super_class: _,
@@ -673,25 +1403,7 @@ impl<'a, FS: Fs + ?Sized> ToTokens for WithFs<'a, TargetEnrichedType, FS> {
                         quote! { serde::de::DeserializeOwned },
                     ],
                 );
-                let wrapper_struct_members = if type_params.is_empty() {
-                    vec![quote! { pub #internal_class_name }]
-                } else {
-                    vec![
-                        quote! { pub #internal_class_name },
-                        quote! { pub std::marker::PhantomData #full_type_params },
-                    ]
-                };
-                let extra_args = if type_params.is_empty() {
-                    Default::default()
-                } else {
-                    quote! { std::marker::PhantomData }
-                };
-                let wrapper_from_internal_args = vec![quote! { internal }, extra_args.clone()];
-                let type_params_with_de_lifetime =
-                    render_type_params_with_lifetimes(type_params, &["de"]);
-                let type_params_with_a_lifetime =
-                    render_type_params_with_lifetimes(type_params, &["a"]);
-                let path = context.js_module_path();
+                let import_attr = context.js_import_attr();
                 let class_ref = to_type_ref(type_name, type_params, &class.context);
                 let parent_classes = || {
                     class
@@ -751,19 +1463,7 @@ impl<'a, FS: Fs + ?Sized> ToTokens for WithFs<'a, TargetEnrichedType, FS> {
                             }
                         }),
                 );
-                let type_env: HashMap<_, _> = type_params
-                    .iter()
-                    .map(|(n, _)| {
-                        (
-                            n.clone(),
-                            TypeRef {
-                                referent: TypeIdent::Builtin(Builtin::PrimitiveAny),
-                                type_params: Default::default(),
-                                context: context.clone(),
-                            },
-                        )
-                    })
-                    .collect();
+                let (type_env, this_env) = member_type_envs(&class_ref, type_params, context);
 
                 let target = quote! { self.0 };
                 let ctor_name = |overloads: &[Ctor], ctor: &Constructor| {
@@ -803,8 +1503,8 @@ impl<'a, FS: Fs + ?Sized> ToTokens for WithFs<'a, TargetEnrichedType, FS> {
                     })
                     .unwrap_or_default();
                 let (member_defs, public_methods): (Vec<TokenStream2>, Vec<TokenStream2>) = members.iter()
-                    .flat_map(|(member_js_name, member)| {
-                        let member_js_ident = format_ident!("{}", member_js_name);
+                    .flat_map(|(key, member)| {
+                        let member_js_name = key.js_name();
                         match member {
                             Member::Constructor(ctor) => {
                                 let overloads = &ctor.overloads;
@@ -848,6 +1548,7 @@ impl<'a, FS: Fs + ?Sized> ToTokens for WithFs<'a, TargetEnrichedType, FS> {
                                     .collect()
                             }
                             Member::Method(func) => {
+                                let member_js_ident = format_ident!("{}", member_js_name);
                                 let is_overloaded = func.overloads.len() > 1;
                                 func.overloads
                                     .iter()
@@ -899,75 +1600,97 @@ impl<'a, FS: Fs + ?Sized> ToTokens for WithFs<'a, TargetEnrichedType, FS> {
                                             fn_group_name
                                         };
                                         let pub_fn = func.exposed_to_rust_generic_wrapper_fn(&fn_name, Some(&target), &internal_fn_name, true, rc, &type_env, in_context);
+                                        let cast_fn = render_cast_fn(&func, &fn_name, None);
 
-                                        (member_def, pub_fn)
+                                        (member_def, quote! { #pub_fn #cast_fn })
                                     })
                                     .collect()
                             }
-                            Member::Property(typ) => {
-                                let resolved_type = typ.resolve_generic_in_env(&type_env).into_owned();
-                                let member_name = to_snake_case_ident(member_js_name);
-                                let setter_name = member_name.prefix_name("set_");
+                            Member::StaticMethod(func) => {
+                                let member_rust_name = static_member_rust_name(members, member_js_name);
+                                let member_js_ident = format_ident!("{}", member_js_name);
+                                let is_overloaded = func.overloads.len() > 1;
+                                func.overloads
+                                    .iter()
+                                    .map(|func| {
+                                        let func = func.resolve_generic_in_env(&type_env);
+                                        let in_context = None;
+                                        let internal = InternalFunc {
+                                            func: &func,
+                                            js_name: &member_rust_name,
+                                            in_context: &in_context,
+                                        };
+                                        let internal_fn_name = internal.to_internal_rust_name();
 
-                                let (internal_getter_name, internal_setter_name) = if resolved_type.serialization_type() == SerializationType::Raw {
-                                    (member_name.clone(), setter_name.clone())
-                                } else {
-                                    (
-                                        InternalFunc::to_internal_rust_ident(&member_name),
-                                        InternalFunc::to_internal_rust_ident(&setter_name),
-                                    )
-                                };
-
-                                let internal_getter = PropertyAccessor {
-                                    property_name: member_name.clone(),
-                                    typ: resolved_type.clone(),
-                                    class_name: TypeIdent::ExactName(internal_class_name.to_string()),
-                                    access_type: AccessType::Getter,
-                                };
-
-                                let internal_setter = PropertyAccessor {
-                                    property_name: member_name.clone(),
-                                    typ: resolved_type,
-                                    class_name: TypeIdent::ExactName(internal_class_name.to_string()),
-                                    access_type: AccessType::Setter,
-                                };
-
-
-                                let member_getter = internal_getter.exposed_to_js_fn_decl(&internal_getter_name, None);
-                                let member_setter = internal_setter.exposed_to_js_fn_decl(&internal_setter_name, None);
-                                let member_def = quote! {
-                                    #[wasm_bindgen(method, structural, catch, getter = #member_js_ident, js_class = #js_name)]
-                                    #member_getter;
-
-                                    #[wasm_bindgen(method, structural, catch, setter = #member_js_ident, js_class = #js_name)]
-                                    #member_setter;
-                                };
-
-                                let getter = PropertyAccessor {
-                                    property_name: member_name.clone(),
-                                    typ: typ.clone(),
-                                    class_name: type_name.clone(),
-                                    access_type: AccessType::Getter,
-                                }.getter_fn();
-
-                                let setter = PropertyAccessor {
-                                    property_name: member_name,
-                                    typ: typ.clone(),
-                                    class_name: type_name.clone(),
-                                    access_type: AccessType::Setter,
-                                }.setter_fn();
-
-                                let rc: Option<&fn(TokenStream2) -> TokenStream2> = None;
-                                let getter_fn = getter.exposed_to_rust_generic_wrapper_fn(&to_snake_case_ident(&member_js_name), Some(&target), &internal_getter_name, true, rc, &type_env, None);
-                                let setter_fn = setter.exposed_to_rust_generic_wrapper_fn(&setter_name, Some(&target), &internal_setter_name, true, rc, &type_env, None);
-
-                                let pub_fn = quote! {
-                                    #getter_fn
-
-                                    #setter_fn
-                                };
-
-                                vec![(member_def, pub_fn)]
+                                        let f = func.exposed_to_js_fn_decl(&internal_fn_name, in_context);
+
+                                        let mut attrs = vec![
+                                            quote! {js_name = #member_js_ident},
+                                            quote! {static_method_of = #internal_class_name},
+                                            quote! {js_class = #js_name},
+                                            quote! {catch},
+                                        ];
+                                        if func.is_variadic() {
+                                            attrs.push(quote! { variadic });
+                                        }
+
+                                        let member_def = quote! {
+                                            #[allow(non_snake_case)]
+                                            #[wasm_bindgen(#(#attrs),*)]
+                                            #f;
+                                        };
+
+                                        let rc: Option<&fn(TokenStream2) -> TokenStream2> = None;
+                                        let fq_internal_fn = internal_fn_name.in_namespace(&internal_class_name);
+                                        let fn_group_name = to_snake_case_ident(&member_rust_name);
+                                        let fn_name = if is_overloaded {
+                                            func.overload_name(&fn_group_name)
+                                        } else {
+                                            fn_group_name
+                                        };
+                                        let pub_fn = func.exposed_to_rust_generic_wrapper_fn(&fn_name, None, &fq_internal_fn, true, rc, &type_env, in_context);
+                                        let cast_fn = render_cast_fn(&func, &fn_name, Some(&quote! { Self }));
+
+                                        (member_def, quote! { #pub_fn #cast_fn })
+                                    })
+                                    .collect()
+                            }
+                            Member::StaticProperty(prop) => {
+                                let member_rust_name = static_member_rust_name(members, member_js_name);
+                                vec![render_static_property_accessors(
+                                    member_js_name,
+                                    &member_rust_name,
+                                    &prop.type_info,
+                                    prop.readonly,
+                                    &internal_class_name,
+                                    js_name,
+                                    &type_env,
+                                    &this_env,
+                                )]
+                            }
+                            Member::Property(prop) => {
+                                vec![render_property_accessors(
+                                    member_js_name,
+                                    &prop.type_info,
+                                    prop.readonly,
+                                    type_name,
+                                    &internal_class_name,
+                                    Some(js_name),
+                                    &type_env,
+                                    &this_env,
+                                )]
+                            }
+                            Member::Iterator(item_type) => {
+                                let fn_name = unique_member_fn_name("iter", members.keys().map(MemberKey::js_name));
+                                let pub_fn = render_iter_fn(&fn_name, &item_type.resolve_generic_in_env(&this_env));
+
+                                vec![(quote! {}, pub_fn)]
+                            }
+                            Member::AsyncIterator(item_type) => {
+                                let fn_name = unique_member_fn_name("stream", members.keys().map(MemberKey::js_name));
+                                let pub_fn = render_stream_fn(&fn_name, &item_type.resolve_generic_in_env(&this_env));
+
+                                vec![(quote! {}, pub_fn)]
                             }
                         }
                     })
@@ -981,9 +1704,16 @@ impl<'a, FS: Fs + ?Sized> ToTokens for WithFs<'a, TargetEnrichedType, FS> {
                     class,
                     &class.context,
                 );
+                let wrapper_type = render_wrapper_type(
+                    &vis,
+                    &name,
+                    &internal_class_name,
+                    type_params,
+                    quote! { #internal_class_name::instanceof(val) },
+                );
 
                 quote! {
-                    #[wasm_bindgen(module = #path)]
+                    #import_attr
                     extern "C" {
                         #[allow(non_camel_case_types)]
                         #[wasm_bindgen(#(#attrs),*)]
@@ -992,114 +1722,17 @@ impl<'a, FS: Fs + ?Sized> ToTokens for WithFs<'a, TargetEnrichedType, FS> {
                         #(#member_defs)*
                     }
 
-                    #[derive(std::clone::Clone)]
-                    #vis struct #name #full_type_params(#(#wrapper_struct_members),*);
+                    #wrapper_type
 
                     #(#super_as_ref_impls)*
 
                     #default_impl
 
-                    impl #full_type_params std::convert::From<#name #full_type_params> for JsValue {
-                        fn from(src: #name #full_type_params) -> JsValue {
-                            JsValue::from(src.0)
-                        }
-                    }
-
-                    impl #full_type_params std::convert::AsRef<JsValue> for #name #full_type_params {
-                        fn as_ref(&self) -> &JsValue {
-                            self.0.as_ref()
-                        }
-                    }
-
-                    impl #full_type_params wasm_bindgen::JsCast for #name #full_type_params {
-                        fn instanceof(val: &JsValue) -> bool {
-                            #internal_class_name::instanceof(val)
-                        }
-                        fn unchecked_from_js(val: JsValue) -> Self {
-                            #name(#internal_class_name::unchecked_from_js(val), #extra_args)
-                        }
-                        fn unchecked_from_js_ref(val: &JsValue) -> &Self {
-                            unsafe {
-                                &*(#internal_class_name::unchecked_from_js_ref(val) as *const #internal_class_name as *const Self)
-                            }
-                        }
-                    }
-
                     impl #full_type_params_deserializable #name #full_type_params {
                         #(#public_methods)*
                     }
 
-                    impl #full_type_params wasm_bindgen::describe::WasmDescribe for #name #full_type_params {
-                        fn describe() {
-                            <#internal_class_name as wasm_bindgen::describe::WasmDescribe>::describe()
-                        }
-                    }
-
-                    impl #full_type_params wasm_bindgen::convert::IntoWasmAbi for #name #full_type_params {
-                        type Abi = <#internal_class_name as wasm_bindgen::convert::IntoWasmAbi>::Abi;
-                        fn into_abi(self) -> Self::Abi {
-                            wasm_bindgen::convert::IntoWasmAbi::into_abi(self.0)
-                        }
-                    }
-
-                    impl #full_type_params wasm_bindgen::convert::FromWasmAbi for #name #full_type_params {
-                        type Abi = <#internal_class_name as wasm_bindgen::convert::FromWasmAbi>::Abi;
-                        unsafe fn from_abi(js: Self::Abi) -> Self {
-                            #name(wasm_bindgen::convert::FromWasmAbi::from_abi(js), #extra_args)
-                        }
-                    }
-
-                    impl #type_params_with_a_lifetime wasm_bindgen::convert::IntoWasmAbi for &'a #name #full_type_params {
-                        type Abi = <&'a #internal_class_name as wasm_bindgen::convert::IntoWasmAbi>::Abi;
-                        fn into_abi(self) -> Self::Abi {
-                            wasm_bindgen::convert::IntoWasmAbi::into_abi(&self.0)
-                        }
-                    }
-
-                    impl #full_type_params serde::ser::Serialize for #name #full_type_params {
-                        fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
-                        where
-                            S: serde::ser::Serializer,
-                        {
-                            serde::ser::Serialize::serialize(&self.0, serializer)
-                        }
-                    }
-
-                    impl #type_params_with_de_lifetime serde::de::Deserialize<'de> for #name #full_type_params {
-                        fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-                        where
-                            D: serde::de::Deserializer<'de>,
-                        {
-                            let internal: #internal_class_name = <#internal_class_name as serde::de::Deserialize>::deserialize(deserializer)?;
-                            std::result::Result::Ok(Self(#(#wrapper_from_internal_args),*))
-                        }
-                    }
-
                     #trait_defn
-
-                    impl std::clone::Clone for #internal_class_name {
-                        fn clone(&self) -> Self {
-                            Self { obj: std::clone::Clone::clone(&self.obj) }
-                        }
-                    }
-
-                    impl serde::ser::Serialize for #internal_class_name {
-                        fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
-                        where
-                            S: serde::ser::Serializer,
-                        {
-                            ts_bindgen_rt::serialize_as_jsvalue(serializer, self)
-                        }
-                    }
-
-                    impl<'de> serde::de::Deserialize<'de> for #internal_class_name {
-                        fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-                        where
-                            D: serde::de::Deserializer<'de>,
-                        {
-                            ts_bindgen_rt::deserialize_as_jsvalue(deserializer)
-                        }
-                    }
                 }
             }
             TargetEnrichedTypeInfo::Intersection(isect) => {
@@ -1130,13 +1763,29 @@ impl<'a, FS: Fs + ?Sized> ToTokens for WithFs<'a, TargetEnrichedType, FS> {
                             .filter_map(|iface| iface.constructor)
                             .next();
 
+                        let callable = interface_types().filter_map(|iface| iface.callable).next();
+
+                        let iterator = interface_types().filter_map(|iface| iface.iterator).next();
+
+                        let async_iterator = interface_types()
+                            .filter_map(|iface| iface.async_iterator)
+                            .next();
+
+                        let readonly_fields = interface_types()
+                            .flat_map(|iface| iface.readonly_fields)
+                            .collect();
+
                         let typ = TargetEnrichedType {
                             name: type_name.clone(),
                             is_exported,
                             info: TargetEnrichedTypeInfo::Interface(Interface {
                                 indexer,
                                 fields,
+                                readonly_fields,
                                 constructor,
+                                callable,
+                                iterator,
+                                async_iterator,
                                 extends: Default::default(),
                                 context: isect.context.clone(),
                                 type_params: Default::default(), // TODO: copy over type params from isect
@@ -1184,6 +1833,22 @@ impl<'a, FS: Fs + ?Sized> ToTokens for WithFs<'a, TargetEnrichedType, FS> {
                     }
                 }
             }
+            TargetEnrichedTypeInfo::NamespaceImport(NamespaceImport::Namespace {
+                src,
+                name_parts,
+                ..
+            }) => {
+                let ns = src
+                    .to_mod_path_iter(*fs)
+                    .chain(name_parts.iter().map(|n| to_snake_case_ident(n)))
+                    .collect::<Vec<_>>()
+                    .to_ns_path(*fs, type_name);
+                let name = to_snake_case_ident(js_name);
+
+                quote! {
+                    #vis use #(#ns)::* as #name;
+                }
+            }
             TargetEnrichedTypeInfo::NamespaceImport(import) => {
                 let (src, import_name) = match import {
                     NamespaceImport::Default { src, context } => {
@@ -1224,7 +1889,7 @@ impl<'a, FS: Fs + ?Sized> ToTokens for WithFs<'a, TargetEnrichedType, FS> {
                         };
                         (src, tr.to_name().1)
                     }
-                    NamespaceImport::All { .. } => {
+                    NamespaceImport::All { .. } | NamespaceImport::Namespace { .. } => {
                         // handled above
                         unreachable!();
                     }
@@ -1445,17 +2110,34 @@ impl<'a> ToTokens for FieldDefinition<'a> {
         }
         let rendered_type = OwnedTypeRef(Cow::Borrowed(typ));
 
-        if typ.serialization_type() == SerializationType::Fn {
-            let serialize_fn = serialize_field_name(&field_name);
-            let deserialize_fn = deserialize_field_name(&field_name);
-            let serialize_fn = format!("{}::{}", self.self_name, serialize_fn);
-            let deserialize_fn = format!("{}::{}", self.self_name, deserialize_fn);
-            serde_attrs.push(quote! {
-                serialize_with = #serialize_fn
-            });
-            serde_attrs.push(quote! {
-                deserialize_with = #deserialize_fn
-            });
+        match typ.serialization_type() {
+            SerializationType::Fn => {
+                let serialize_fn = serialize_field_name(&field_name);
+                let deserialize_fn = deserialize_field_name(&field_name);
+                let serialize_fn = format!("{}::{}", self.self_name, serialize_fn);
+                let deserialize_fn = format!("{}::{}", self.self_name, deserialize_fn);
+                serde_attrs.push(quote! {
+                    serialize_with = #serialize_fn
+                });
+                serde_attrs.push(quote! {
+                    deserialize_with = #deserialize_fn
+                });
+            }
+            SerializationType::JsValue => {
+                serde_attrs.push(quote! {
+                    serialize_with = "ts_bindgen_rt::serialize_jsvalue"
+                });
+                serde_attrs.push(quote! {
+                    deserialize_with = "ts_bindgen_rt::deserialize_as_jsvalue"
+                });
+            }
+            _ => {
+                if let Some(serialize_fn) = bigint_serialize_with(typ) {
+                    serde_attrs.push(quote! {
+                        serialize_with = #serialize_fn
+                    });
+                }
+            }
         };
 
         let our_toks = quote! {
@@ -1519,67 +2201,444 @@ impl ToTokens for EnumValue {
         let our_toks = match self {
             EnumValue::Str(s) => quote! { #s },
             EnumValue::Num(n) => quote! { #n },
+            EnumValue::Bool(b) => quote! { #b },
         };
 
         toks.append_all(our_toks);
     }
 }
 
-fn render_deserialize_fn(
-    field_name: &Identifier,
-    type_info: &TargetEnrichedTypeInfo,
-) -> Option<TokenStream2> {
-    let (f, rendered_type) = match type_info {
-        TargetEnrichedTypeInfo::Ref(
-            tr @ TypeRef {
-                referent: TypeIdent::Builtin(Builtin::Fn),
-                ..
-            },
-        ) => {
-            let rendered_type = OwnedTypeRef(Cow::Borrowed(tr));
-            (tr as &dyn HasFnPrototype, quote! { #rendered_type })
-        }
-        TargetEnrichedTypeInfo::FuncGroup(fg) => {
-            let rendered_type = OwnedTypeInfo { type_info };
-            (
-                &fg.widened_fn as &dyn HasFnPrototype,
-                quote! { #rendered_type },
-            )
+/// Returns `desired`, made unique against the rust names of the members named
+/// `member_js_names`, for methods we add to wrappers.
+fn unique_member_fn_name<S: AsRef<str>>(
+    desired: &str,
+    member_js_names: impl Iterator<Item = S>,
+) -> Identifier {
+    let taken: HashSet<String> = member_js_names
+        .map(|n| to_snake_case_ident(n).to_string())
+        .collect();
+    to_unique_ident(desired.to_string(), &|n| taken.contains(n))
+}
+
+/// Renders a method, named `fn_name`, iterating over the items produced by
+/// `[Symbol.iterator]` of a wrapped js object.
+fn render_iter_fn(fn_name: &Identifier, item_type: &TypeRef) -> TokenStream2 {
+    quote! {
+        pub fn #fn_name(&self) -> std::result::Result<impl std::iter::Iterator<Item = std::result::Result<#item_type, JsValue>>, JsValue> {
+            let this: &JsValue = self.0.as_ref();
+            let items = js_sys::try_iter(this)?
+                .ok_or_else(|| JsValue::from_str("object is not iterable"))?;
+            Ok(items.map(|item| {
+                ts_bindgen_rt::from_jsvalue(&item?).map_err(ts_bindgen_rt::Error::from).map_err(JsValue::from)
+            }))
         }
-        _ => {
-            return None;
+    }
+}
+
+/// Renders a method, named `fn_name`, returning a stream of the items produced
+/// by `[Symbol.asyncIterator]` of a wrapped js object.
+///
+/// Streams require the `futures` feature of ts-bindgen-rt so we only render
+/// them if async iter streams are enabled.
+fn render_stream_fn(fn_name: &Identifier, item_type: &TypeRef) -> TokenStream2 {
+    if !item_type.context.type_options.async_iter_streams {
+        return quote! {};
+    }
+
+    quote! {
+        pub fn #fn_name(&self) -> ts_bindgen_rt::AsyncIterStream<#item_type> {
+            let this: &JsValue = self.0.as_ref();
+            ts_bindgen_rt::AsyncIterStream::from(this.clone())
         }
+    }
+}
+
+/// Camel cases the name of an enum member, keeping underscores between digits,
+/// which separate the integer and fractional parts of numeric literal members
+/// (`Neg1_5` rather than `Neg15`).
+fn enum_member_name(id: &str) -> String {
+    let is_digit = |c: Option<char>| c.map(|c| c.is_ascii_digit()).unwrap_or(false);
+    let mut parts = vec![String::new()];
+    let mut chars = id.chars().peekable();
+    let mut prev = None;
+    while let Some(c) = chars.next() {
+        if c == '_' && is_digit(prev) && is_digit(chars.peek().copied()) {
+            parts.push(String::new());
+        } else if let Some(part) = parts.last_mut() {
+            part.push(c);
+        }
+        prev = Some(c);
+    }
+
+    let (first, rest) = parts.split_first().expect("parts is never empty");
+    iter::once(to_camel_case_ident(first).to_string())
+        .chain(rest.iter().map(|p| p.to_camel_case()))
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Returns the rust variant names for `members`, disambiguating members whose
+/// names collide once converted to rust identifiers (e.g. `"a" | "A"`).
+fn enum_member_idents(members: &[EnumMember]) -> Vec<Identifier> {
+    let mut taken: HashSet<String> = Default::default();
+    members
+        .iter()
+        .map(|m| {
+            let id = to_unique_ident(enum_member_name(&m.id), &|n| taken.contains(n));
+            taken.insert(id.to_string());
+            id
+        })
+        .collect()
+}
+
+/// Renders `Display` and `FromStr` implementations for a fieldless enum, converting
+/// each member (named by the corresponding entry of `ids`) to and from the string
+/// form of its value.
+fn render_enum_str_conversions(
+    name: &Identifier,
+    ids: &[Identifier],
+    members: &[EnumMember],
+) -> TokenStream2 {
+    let (ids, values): (Vec<_>, Vec<_>) = ids
+        .iter()
+        .zip(members.iter())
+        .filter_map(|(id, m)| {
+            let value = match m.value.as_ref()? {
+                EnumValue::Str(s) => s.clone(),
+                EnumValue::Num(n) => n.to_string(),
+                EnumValue::Bool(b) => b.to_string(),
+            };
+            Some((id, value))
+        })
+        .unzip();
+
+    quote! {
+        impl std::fmt::Display for #name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(match self {
+                    #(#name::#ids => #values),*
+                })
+            }
+        }
+
+        impl std::str::FromStr for #name {
+            type Err = ts_bindgen_rt::Error;
+
+            fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                match s {
+                    #(#values => Ok(#name::#ids),)*
+                    _ => Err(ts_bindgen_rt::Error),
+                }
+            }
+        }
+    }
+}
+
+/// Renders a `String` newtype for a template literal type, e.g. `` `#${string}` ``.
+///
+/// The newtype may only be constructed from (or deserialized from) strings matching
+/// the template.
+fn render_template_literal(name: &Identifier, template: &TemplateLiteral) -> TokenStream2 {
+    let TemplateLiteral { quasis, holes, .. } = template;
+    let mut quasis = quasis.iter();
+    let parts = quasis
+        .next()
+        .map(|q| quote! { ts_bindgen_rt::template_literal::TemplatePart::Literal(#q) })
+        .into_iter()
+        .chain(holes.iter().zip(quasis).flat_map(|(hole, quasi)| {
+            let hole = match hole {
+                TemplateHole::OneOf(options) => quote! {
+                    ts_bindgen_rt::template_literal::TemplatePart::OneOf(&[#(#options),*])
+                },
+                TemplateHole::String => {
+                    quote! { ts_bindgen_rt::template_literal::TemplatePart::String }
+                }
+                TemplateHole::Number => {
+                    quote! { ts_bindgen_rt::template_literal::TemplatePart::Number }
+                }
+                TemplateHole::BigInt => {
+                    quote! { ts_bindgen_rt::template_literal::TemplatePart::BigInt }
+                }
+            };
+            [
+                hole,
+                quote! { ts_bindgen_rt::template_literal::TemplatePart::Literal(#quasi) },
+            ]
+        }));
+
+    quote! {
+        #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+        #[serde(try_from = "String", into = "String")]
+        pub struct #name(String);
+
+        impl #name {
+            /// Returns the provided string if it matches our template
+            pub fn new(s: impl Into<String>) -> std::result::Result<Self, ts_bindgen_rt::Error> {
+                let s = s.into();
+                if ts_bindgen_rt::template_literal::matches(&s, &[#(#parts),*]) {
+                    Ok(#name(s))
+                } else {
+                    Err(ts_bindgen_rt::Error)
+                }
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl std::convert::TryFrom<String> for #name {
+            type Error = ts_bindgen_rt::Error;
+
+            fn try_from(s: String) -> std::result::Result<Self, Self::Error> {
+                #name::new(s)
+            }
+        }
+
+        impl From<#name> for String {
+            fn from(src: #name) -> String {
+                src.0
+            }
+        }
+
+        impl AsRef<str> for #name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl std::fmt::Display for #name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl std::str::FromStr for #name {
+            type Err = ts_bindgen_rt::Error;
+
+            fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                #name::new(s)
+            }
+        }
+    }
+}
+
+/// Renders a tuple struct for a typescript tuple type.
+///
+/// Optional elements are rendered as `Option`s that are omitted when serializing and a
+/// trailing rest element is rendered as a `Vec` whose items are spread into the
+/// serialized sequence. Labeled elements get accessor methods named after their labels.
+fn render_tuple(name: &Identifier, js_name: &str, tuple: &Tuple) -> TokenStream2 {
+    let Tuple {
+        types,
+        labels,
+        has_rest,
+        ..
+    } = tuple;
+    let is_optional = |t: &TypeRef| t.referent == TypeIdent::Builtin(Builtin::Optional);
+    let rest_idx = if *has_rest {
+        types.len().checked_sub(1)
+    } else {
+        None
     };
-    let deserialize_fn_name = deserialize_field_name(field_name);
-    let return_type = f.return_type();
-    let return_value = quote! { ret };
-    let ret = render_raw_return_to_js(&return_type, &return_value);
-    let args = quote! { _Args };
-    let params = f
-        .params()
-        .map(|p| p.as_exposed_to_rust_named_param_list(None));
-    // TODO: need to render wrappers for fn params, used in rust_to_jsvalue_conversion
-    let conversions = f.args().map(|p| {
-        let name = p.rust_name();
-        let conv = p.rust_to_jsvalue_conversion(None);
+    let indices = (0..types.len()).map(syn::Index::from);
+
+    let accessors = labels
+        .iter()
+        .zip(types.iter())
+        .zip(indices.clone())
+        .enumerate()
+        .map(|(i, ((label, typ), idx))| {
+            let fn_name = to_snake_case_ident(label);
+            if Some(i) == rest_idx {
+                let item_type = typ.type_params.first();
+                quote! {
+                    pub fn #fn_name(&self) -> &[#item_type] {
+                        &self.#idx
+                    }
+                }
+            } else if is_optional(typ) {
+                let item_type = typ.type_params.first();
+                quote! {
+                    pub fn #fn_name(&self) -> Option<&#item_type> {
+                        self.#idx.as_ref()
+                    }
+                }
+            } else {
+                quote! {
+                    pub fn #fn_name(&self) -> &#typ {
+                        &self.#idx
+                    }
+                }
+            }
+        });
+    let accessors = if labels.is_empty() {
+        quote! {}
+    } else {
         quote! {
-            let #name = #conv;
+            impl #name {
+                #(#accessors)*
+            }
         }
-    });
-    let pushes = f.params().map(|p| {
-        let name = p.rust_name();
-        if p.is_variadic() {
-            quote! {
-                for #name in #name.into_iter(){
-                    #args.push(&#name);
+    };
+
+    if rest_idx.is_none() {
+        let fields = types.iter().map(|t| {
+            if is_optional(t) {
+                quote! {
+                    #[serde(default, skip_serializing_if = "Option::is_none")]
+                    pub #t
                 }
+            } else {
+                quote! { pub #t }
             }
-        } else {
-            quote! {
-                #args.push(&#name);
+        });
+
+        return quote! {
+            #[derive(Clone, serde::Serialize, serde::Deserialize)]
+            pub struct #name(#(#fields),*);
+
+            #accessors
+        };
+    }
+
+    let vars: Vec<_> = (0..types.len())
+        .map(|i| format_ident!("elem_{}", i))
+        .collect();
+    let (serialize_lens, serialize_elems): (Vec<_>, Vec<_>) = types
+        .iter()
+        .zip(indices)
+        .enumerate()
+        .map(|(i, (typ, idx))| {
+            if Some(i) == rest_idx {
+                (
+                    quote! { self.#idx.len() },
+                    quote! {
+                        for item in &self.#idx {
+                            seq.serialize_element(item)?;
+                        }
+                    },
+                )
+            } else if is_optional(typ) {
+                (
+                    quote! { self.#idx.is_some() as usize },
+                    quote! {
+                        if let Some(item) = &self.#idx {
+                            seq.serialize_element(item)?;
+                        }
+                    },
+                )
+            } else {
+                (
+                    quote! { 1 },
+                    quote! {
+                        seq.serialize_element(&self.#idx)?;
+                    },
+                )
+            }
+        })
+        .unzip();
+    let deserialize_elems = types
+        .iter()
+        .zip(vars.iter())
+        .enumerate()
+        .map(|(i, (typ, var))| {
+            if Some(i) == rest_idx {
+                quote! {
+                    let mut #var: #typ = Vec::new();
+                    while let Some(item) = seq.next_element()? {
+                        #var.push(item);
+                    }
+                }
+            } else if is_optional(typ) {
+                quote! {
+                    let #var = seq.next_element::<#typ>()?.flatten();
+                }
+            } else {
+                quote! {
+                    let #var = seq
+                        .next_element::<#typ>()?
+                        .ok_or_else(|| serde::de::Error::invalid_length(#i, &self))?;
+                }
+            }
+        });
+
+    quote! {
+        #[derive(Clone)]
+        pub struct #name(#(pub #types),*);
+
+        impl serde::Serialize for #name {
+            fn serialize<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> std::result::Result<S::Ok, S::Error> {
+                use serde::ser::SerializeSeq;
+                let mut seq = serializer.serialize_seq(Some(#(#serialize_lens)+*))?;
+                #(#serialize_elems)*
+                seq.end()
             }
         }
-    });
+
+        impl<'de> serde::Deserialize<'de> for #name {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> std::result::Result<Self, D::Error> {
+                struct TupleVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for TupleVisitor {
+                    type Value = #name;
+
+                    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                        f.write_str(concat!("tuple ", #js_name))
+                    }
+
+                    fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                        self,
+                        mut seq: A,
+                    ) -> std::result::Result<Self::Value, A::Error> {
+                        #(#deserialize_elems)*
+                        Ok(#name(#(#vars),*))
+                    }
+                }
+
+                deserializer.deserialize_seq(TupleVisitor)
+            }
+        }
+
+        #accessors
+    }
+}
+
+fn render_deserialize_fn(
+    field_name: &Identifier,
+    type_info: &TargetEnrichedTypeInfo,
+) -> Option<TokenStream2> {
+    let (f, rendered_type) = match type_info {
+        TargetEnrichedTypeInfo::Ref(
+            tr @ TypeRef {
+                referent: TypeIdent::Builtin(Builtin::Fn),
+                ..
+            },
+        ) => {
+            let rendered_type = OwnedTypeRef(Cow::Borrowed(tr));
+            (tr as &dyn HasFnPrototype, quote! { #rendered_type })
+        }
+        TargetEnrichedTypeInfo::FuncGroup(fg) => {
+            let rendered_type = OwnedTypeInfo { type_info };
+            (
+                &fg.widened_fn as &dyn HasFnPrototype,
+                quote! { #rendered_type },
+            )
+        }
+        _ => {
+            return None;
+        }
+    };
+    let deserialize_fn_name = deserialize_field_name(field_name);
+    let params = f
+        .params()
+        .map(|p| p.as_exposed_to_rust_named_param_list(None));
+    let invocation = render_js_fn_apply(f, &quote! { f });
     // TODO: do we need to handle member functions here (first arg to apply may be
     // non-null)
     Some(quote! {
@@ -1593,11 +2652,7 @@ fn render_deserialize_fn(
             #field_name.map(|f| {
                 let f = f.clone();
                 std::rc::Rc::new(move |#(#params),*| {
-                    #(#conversions);*
-                    let #args = js_sys::Array::new();
-                    #(#pushes);*
-                    let #return_value = f.apply(&JsValue::null(), &#args)?;
-                    Ok(#ret)
+                    #invocation
                 }) as #rendered_type
             })
             .ok_or_else(|| ts_bindgen_rt::jsvalue_serde::Error::InvalidType("expected function".to_string()))
@@ -1651,7 +2706,10 @@ fn render_serialize_fn(
 
 #[cfg(test)]
 mod test {
-    use crate::{generate_rust_for_typescript, Error, MemFs};
+    use crate::{
+        generate_rust_for_typescript, generate_rust_for_typescript_with_options, BigIntMapping,
+        Error, GenerateOptions, JsImportMode, MemFs,
+    };
     use std::path::Path;
 
     fn ts_to_rust(code: &str) -> Result<String, Error> {
@@ -1860,4 +2918,718 @@ mod test {
         ));
         Ok(())
     }
+
+    #[test]
+    fn test_keyof_param() -> Result<(), Error> {
+        let rust = ts_to_rust(
+            r#"
+            export interface Options {
+                someOption: string;
+            }
+            export declare function get(key: keyof Options): void;
+        "#,
+        )?;
+
+        assert!(rust
+            .replace(" ", "")
+            .contains(&r#"#[serde(rename = "someOption")] SomeOption"#.replace(" ", "")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_polymorphic_this() -> Result<(), Error> {
+        let rust = ts_to_rust(
+            r#"
+            export declare class Builder {
+                setName(name: string): this;
+            }
+        "#,
+        )?;
+
+        assert!(rust.replace(" ", "").contains(
+            &"pub fn set_name(&self, name: String) -> std::result::Result<Builder, JsValue>"
+                .replace(" ", "")
+        ));
+        assert!(rust.replace(" ", "").contains(
+            &"fn set_name(&self, name: String) -> std::result::Result<Self, JsValue>;"
+                .replace(" ", "")
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_polymorphic_this_in_interface() -> Result<(), Error> {
+        let rust = ts_to_rust(
+            r#"
+            export interface Something {
+                thisThing: this;
+            }
+        "#,
+        )?;
+        let rust = rust.replace(" ", "");
+
+        // a struct can't contain itself so `this` fields hold a js value
+        assert!(rust.contains(
+            r#"deserialize_with="ts_bindgen_rt::deserialize_as_jsvalue")]pubthis_thing:wasm_bindgen::JsValue"#
+        ));
+        assert!(rust.contains(
+            &"fn this_thing(&self) -> std::result::Result<Self, JsValue>;".replace(" ", "")
+        ));
+        assert!(rust.contains("value:Self)->std::result::Result<(),JsValue>;"));
+        assert!(rust.contains("ts_bindgen_rt::from_jsvalue(&self.this_thing)"));
+        assert!(!rust.contains("pubthis_thing:Self"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_interface_call_signature() -> Result<(), Error> {
+        let rust = ts_to_rust(
+            r#"
+            export interface Counter {
+                (step: number): number;
+                count: number;
+            }
+        "#,
+        )?;
+
+        assert!(rust
+            .replace(" ", "")
+            .contains(&"#[wasm_bindgen(extends = js_sys::Function)]".replace(" ", "")));
+        assert!(rust.replace(" ", "").contains(
+            &"pub fn call(&self, step: f64) -> std::result::Result<f64, JsValue>".replace(" ", "")
+        ));
+        assert!(rust.replace(" ", "").contains(
+            &"pub fn count(&self) -> std::result::Result<f64, JsValue>".replace(" ", "")
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_inherited_call_signature() -> Result<(), Error> {
+        let rust = ts_to_rust(
+            r#"
+            interface Counter {
+                (step: number): number;
+            }
+
+            export interface NamedCounter extends Counter {
+                call: string;
+            }
+        "#,
+        )?;
+        let rust = rust.replace(" ", "");
+
+        assert!(rust.contains("#[wasm_bindgen(extends=js_sys::Function)]"));
+        assert!(rust.contains("pubfncall_(&self,step:f64)->std::result::Result<f64,JsValue>"));
+        assert!(rust.contains("pubfncall(&self)->std::result::Result<String,JsValue>"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_class_static_members() -> Result<(), Error> {
+        let rust = ts_to_rust(
+            r#"
+            export declare class Counter {
+                static create(start: number): Counter;
+                static total: number;
+            }
+        "#,
+        )?;
+
+        assert!(rust
+            .replace(" ", "")
+            .contains(&"static_method_of = Counter_Class".replace(" ", "")));
+        assert!(rust.replace(" ", "").contains(
+            &"pub fn create(start: f64) -> std::result::Result<Counter, JsValue>".replace(" ", "")
+        ));
+        assert!(rust
+            .replace(" ", "")
+            .contains(&"pub fn total() -> std::result::Result<f64, JsValue>".replace(" ", "")));
+        assert!(rust.replace(" ", "").contains(
+            &"pub fn set_total(value: f64) -> std::result::Result<(), JsValue>".replace(" ", "")
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_class_static_and_instance_members_with_same_name() -> Result<(), Error> {
+        let rust = ts_to_rust(
+            r#"
+            export declare class Thing {
+                static from(s: string): Thing;
+                from(): string;
+                static count: number;
+                count: number;
+            }
+        "#,
+        )?;
+        let rust = rust.replace(" ", "");
+
+        assert!(rust.contains("pubfnstatic_from(s:String)->std::result::Result<Thing,JsValue>"));
+        assert!(rust.contains("pubfnfrom(&self)->std::result::Result<String,JsValue>"));
+        assert!(rust.contains("js_name=from,static_method_of=Thing_Class"));
+        assert!(rust.contains("pubfnstatic_count()->std::result::Result<f64,JsValue>"));
+        assert!(rust.contains("pubfnset_static_count(value:f64)"));
+        assert!(rust.contains("getter=count"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_umd_global_bindings() -> Result<(), Error> {
+        let code = r#"
+            export as namespace Lib;
+            export declare function doThing(n: number): void;
+            export declare class Thing {
+                name: string;
+            }
+        "#;
+        let fs = || {
+            let mut fs: MemFs = Default::default();
+            fs.set_cwd(Path::new("/"));
+            fs.add_file_at(Path::new("/test.d.ts"), code.to_string());
+            fs
+        };
+
+        let global = generate_rust_for_typescript_with_options(
+            fs(),
+            "/test",
+            GenerateOptions {
+                import_mode: JsImportMode::Global,
+                ..Default::default()
+            },
+            |_| {},
+        )?
+        .to_string()
+        .replace(" ", "");
+        assert!(global.contains("#[wasm_bindgen(js_namespace=Lib)]extern\"C\""));
+        assert!(!global.contains("module="));
+
+        let module = generate_rust_for_typescript_with_options(
+            fs(),
+            "/test",
+            GenerateOptions {
+                import_mode: JsImportMode::Module,
+                ..Default::default()
+            },
+            |_| {},
+        )?
+        .to_string()
+        .replace(" ", "");
+        assert!(module.contains("module="));
+        assert!(!module.contains("js_namespace"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_umd_global_bindings_with_dependencies() -> Result<(), Error> {
+        let mut fs: MemFs = Default::default();
+        fs.set_cwd(Path::new("/"));
+        fs.add_file_at(
+            Path::new("/test.d.ts"),
+            r#"
+                export as namespace Lib;
+                export * from "./reexported";
+                import { DepThing } from "./dep";
+                import { OtherThing } from "./other";
+                export declare function useThings(d: DepThing, o: OtherThing): void;
+            "#
+            .to_string(),
+        );
+        fs.add_file_at(
+            Path::new("/reexported.d.ts"),
+            r#"export declare function reexportedFn(): void;"#.to_string(),
+        );
+        fs.add_file_at(
+            Path::new("/dep.d.ts"),
+            r#"
+                export as namespace DepLib;
+                export declare function depFn(): void;
+                export interface DepThing {}
+            "#
+            .to_string(),
+        );
+        fs.add_file_at(
+            Path::new("/other.d.ts"),
+            r#"
+                export declare function otherFn(): void;
+                export interface OtherThing {}
+            "#
+            .to_string(),
+        );
+
+        let rust = generate_rust_for_typescript_with_options(
+            fs,
+            "/test",
+            GenerateOptions {
+                import_mode: JsImportMode::Global,
+                ..Default::default()
+            },
+            |_| {},
+        )?
+        .to_string()
+        .replace(" ", "");
+
+        assert!(rust.contains("#[wasm_bindgen(js_namespace=Lib)]extern\"C\"{#[allow(non_snake_case)]#[wasm_bindgen(js_name=\"reexportedFn\""));
+        assert!(rust.contains("#[wasm_bindgen(js_namespace=DepLib)]extern\"C\"{#[allow(non_snake_case)]#[wasm_bindgen(js_name=\"depFn\""));
+        assert!(rust.contains(
+            "other.js\")]extern\"C\"{#[allow(non_snake_case)]#[wasm_bindgen(js_name=\"otherFn\""
+        ));
+        assert!(!rust.contains("test.js"));
+        assert!(!rust.contains("reexported.js"));
+        assert!(!rust.contains("dep.js"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_declare_global() -> Result<(), Error> {
+        let rust = ts_to_rust(
+            r#"
+            export declare function local(): void;
+            declare global {
+                function topLevel(): void;
+                namespace MyLib {
+                    function nested(): void;
+                }
+            }
+        "#,
+        )?;
+        let rust = rust.replace(" ", "");
+
+        assert!(rust.contains("#[wasm_bindgen]extern\"C\"{#[allow(non_snake_case)]#[wasm_bindgen(js_name=\"topLevel\""));
+        assert!(rust.contains("#[wasm_bindgen(js_namespace=MyLib)]extern\"C\""));
+        assert!(rust.contains("#[wasm_bindgen(module="));
+        Ok(())
+    }
+
+    #[test]
+    fn test_global_vars() -> Result<(), Error> {
+        let rust = ts_to_rust(
+            r#"
+            export interface MyLib {
+                version: string;
+            }
+            declare global {
+                var myLib: MyLib;
+                var debug: any;
+            }
+        "#,
+        )?;
+        let rust = rust.replace(" ", "");
+
+        assert!(rust.contains(
+            r#"#[wasm_bindgen]extern"C"{#[allow(non_upper_case_globals)]#[wasm_bindgen(js_name="myLib")]static__TSB_my_lib:JsValue;}"#
+        ));
+        assert!(rust.contains("pubfnmy_lib()->std::result::Result<"));
+        assert!(rust.contains("ts_bindgen_rt::from_jsvalue(&value)"));
+        assert!(rust.contains("static__TSB_debug:JsValue;"));
+        assert!(rust.contains("Ok(wasm_bindgen::JsCast::unchecked_into(value))"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_merged_interfaces() -> Result<(), Error> {
+        let rust = ts_to_rust(
+            r#"
+            export interface Options {
+                base: number;
+            }
+            export interface Options {
+                extra: string;
+                onExtra(): void;
+            }
+        "#,
+        )?;
+        let rust = rust.replace(" ", "");
+
+        assert!(rust.contains("pubbase:f64"));
+        assert!(rust.contains("pubextra:String"));
+        assert!(rust.contains("pubon_extra:std::rc::Rc<dynFn()"));
+        assert!(rust.contains("fnon_extra(&self)"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_type_param_constraints_and_defaults() -> Result<(), Error> {
+        let rust = ts_to_rust(
+            r#"
+            export interface Base {
+                name: string;
+            }
+            export interface Wrapper<T extends Base, U = string> {
+                item: T;
+                other: U;
+            }
+        "#,
+        )?;
+        let rust = rust.replace(" ", "");
+
+        assert!(rust.contains("pubstructWrapper<T,U=String>"));
+        assert!(rust.contains("traitWrapper_Trait<T,U=String>"));
+        assert!(rust.contains("<T:Base_Trait+std::clone::Clone+serde::ser::Serialize"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_numeric_enum() -> Result<(), Error> {
+        let rust = ts_to_rust(
+            r#"
+            export enum Dir {
+                Up,
+                Down = 1 << 2,
+                Left,
+            }
+        "#,
+        )?;
+        let rust = rust.replace(" ", "");
+
+        assert!(rust.contains("#[repr(i32)]pubenumDir{Up=0,Down=4,Left=5}"));
+        assert!(rust.contains("Dir::Down=>serializer.serialize_f64(4f64)"));
+        assert!(rust.contains("EnumValue::Num(n)ifn==5f64=>Ok(Dir::Left)"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_mixed_const_enum() -> Result<(), Error> {
+        let rust = ts_to_rust(
+            r#"
+            export declare const enum Mixed {
+                Half = 0.5,
+                Name = "name",
+            }
+        "#,
+        )?;
+        let rust = rust.replace(" ", "");
+
+        assert!(rust.contains("pubenumMixed{Half,Name}"));
+        assert!(rust.contains("Mixed::Name=>serializer.serialize_str(\"name\")"));
+        assert!(rust.contains("EnumValue::Str(s)ifs==\"name\"=>Ok(Mixed::Name)"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_literal_union_enums() -> Result<(), Error> {
+        let rust = ts_to_rust(
+            r#"
+            export type Align = "left" | "center" | "right";
+            export interface Style {
+                align?: "start" | "end" | undefined;
+                level: 1 | 2;
+            }
+        "#,
+        )?;
+        let rust = rust.replace(" ", "");
+
+        assert!(rust.contains("#[serde(rename=\"left\")]Left,#[serde(rename=\"center\")]Center"));
+        assert!(rust.contains("\"center\"=>Ok("));
+        assert!(rust.contains("implstd::str::FromStrfor"));
+        assert!(rust.contains("implstd::fmt::Displayfor"));
+        assert!(rust.contains("#[serde(rename=\"start\")]Start,#[serde(rename=\"end\")]End"));
+        assert!(rust.contains("{N1=1,N2=2}"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_literal_union_enum_name_collisions() -> Result<(), Error> {
+        let rust = ts_to_rust(
+            r#"
+            export type Codes = "100" | "200";
+            export type Dashed = "foo-bar" | "fooBar";
+            export type Cased = "a" | "A";
+            export type Fractions = -1.5 | -15;
+        "#,
+        )?;
+        let rust = rust.replace(" ", "");
+
+        assert!(rust.contains("#[serde(rename=\"100\")]N100,#[serde(rename=\"200\")]N200"));
+        assert!(
+            rust.contains("#[serde(rename=\"foo-bar\")]FooBar,#[serde(rename=\"fooBar\")]FooBar_")
+        );
+        assert!(rust.contains("#[serde(rename=\"a\")]A,#[serde(rename=\"A\")]A_"));
+        assert!(rust.contains("{Neg1_5,Neg15}"));
+        assert!(rust.contains("\"fooBar\"=>Ok(Dashed::FooBar_)"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_tuple_elements() -> Result<(), Error> {
+        let rust = ts_to_rust(
+            r#"
+            export type Opt = [name: string, count?: number];
+            export type Rest = [string, ...number[]];
+        "#,
+        )?;
+        let rust = rust.replace(" ", "");
+
+        assert!(rust.contains(
+            "(pubString,#[serde(default,skip_serializing_if=\"Option::is_none\")]pubOption<f64>);"
+        ));
+        assert!(rust.contains("pubfnname(&self)->&String"));
+        assert!(rust.contains("pubfncount(&self)->Option<&f64>"));
+        assert!(rust.contains("(pubString,pubVec<f64>);"));
+        assert!(rust.contains("implserde::Serializefor"));
+        assert!(rust.contains("deserializer.deserialize_seq(TupleVisitor)"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_readonly_members() -> Result<(), Error> {
+        let rust = ts_to_rust(
+            r#"
+            export declare class Counter {
+                readonly count: number;
+                label: string;
+                add(values: readonly number[]): void;
+            }
+        "#,
+        )?;
+        let rust = rust.replace(" ", "");
+
+        assert!(rust.contains("getter=count"));
+        assert!(!rust.contains("setter=count"));
+        assert!(!rust.contains("fnset_count("));
+        assert!(rust.contains("setter=label"));
+        assert!(rust.contains("fnset_label("));
+        assert!(rust.contains("values:&[f64]"));
+
+        let rust = ts_to_rust(
+            r#"
+            interface Sized {
+                readonly size: number;
+            }
+            export interface Handler extends Sized {
+                (x: number): void;
+                name: string;
+            }
+        "#,
+        )?;
+        let rust = rust.replace(" ", "");
+
+        assert!(rust.contains("getter=size"));
+        assert!(!rust.contains("setter=size"));
+        assert!(rust.contains("setter=name"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_iterable_class() -> Result<(), Error> {
+        let rust = ts_to_rust(
+            r#"
+            export declare class Segments {
+                length: number;
+                [Symbol.iterator](): IterableIterator<string>;
+            }
+        "#,
+        )?;
+        let rust = rust.replace(" ", "");
+
+        assert!(rust.contains(
+            "pubfniter(&self)->std::result::Result<implstd::iter::Iterator<Item=std::result::Result<String,JsValue>>,JsValue>"
+        ));
+        assert!(rust.contains("js_sys::try_iter(this)"));
+        assert!(rust.contains("getter=length"));
+        Ok(())
+    }
+
+    fn ts_to_rust_with_streams(ts: &str) -> Result<String, Error> {
+        let mut fs: MemFs = Default::default();
+        fs.set_cwd(Path::new("/"));
+        fs.add_file_at(Path::new("/test.d.ts"), ts.to_string());
+        Ok(generate_rust_for_typescript_with_options(
+            fs,
+            "/test",
+            GenerateOptions {
+                async_iter_streams: true,
+                ..Default::default()
+            },
+            |_| {},
+        )?
+        .to_string())
+    }
+
+    #[test]
+    fn test_iterable_interface() -> Result<(), Error> {
+        let rust = ts_to_rust_with_streams(
+            r#"
+            export interface Registry {
+                size: number;
+                iter(): void;
+                [Symbol.iterator](): IterableIterator<string>;
+                [Symbol.asyncIterator](): AsyncIterableIterator<number>;
+            }
+        "#,
+        )?;
+        let rust = rust.replace(" ", "");
+
+        assert!(rust.contains("#[wasm_bindgen(extends=js_sys::Object)]pubtypeRegistry_Class"));
+        assert!(rust.contains(
+            "pubfniter_(&self)->std::result::Result<implstd::iter::Iterator<Item=std::result::Result<String,JsValue>>,JsValue>"
+        ));
+        assert!(rust.contains("pubfnstream(&self)->ts_bindgen_rt::AsyncIterStream<f64>"));
+        assert!(rust.contains("getter=size"));
+        assert!(!rust.contains("pubsize:f64"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_async_iterables() -> Result<(), Error> {
+        let code = r#"
+            export function pages(): AsyncIterableIterator<string>;
+
+            export declare class Feed {
+                [Symbol.asyncIterator](): AsyncIterableIterator<number>;
+            }
+
+            export interface Page {
+                items: AsyncIterable<string>;
+            }
+        "#;
+
+        let rust = ts_to_rust_with_streams(code)?.replace(" ", "");
+        assert!(rust.contains("ts_bindgen_rt::AsyncIterStream<String>"));
+        assert!(rust.contains("pubfnstream(&self)->ts_bindgen_rt::AsyncIterStream<f64>"));
+
+        // streams require the futures feature of ts-bindgen-rt so they're opt-in
+        let rust = ts_to_rust(code)?.replace(" ", "");
+        assert!(!rust.contains("AsyncIterStream"));
+        assert!(!rust.contains("pubfnstream"));
+        assert!(rust.contains("pubitems:JsValue"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_type_predicates() -> Result<(), Error> {
+        let rust = ts_to_rust(
+            r#"
+            export interface Node {
+                id: string;
+            }
+
+            export function isNode(x: any): x is Node;
+
+            export function assertIsNode(x: any): asserts x is Node;
+        "#,
+        )?;
+        let rust = rust.replace(" ", "");
+
+        assert!(rust.contains("pubfnis_node(x:JsValue)->std::result::Result<bool,JsValue>"));
+        assert!(rust.contains(
+            "pubfnis_node_and_cast(x:JsValue)->std::result::Result<Option<Node>,JsValue>"
+        ));
+        assert!(rust.contains("pubfnassert_is_node(x:JsValue)->std::result::Result<(),JsValue>"));
+        assert!(!rust.contains("assert_is_node_and_cast"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_type_predicate_methods() -> Result<(), Error> {
+        let rust = ts_to_rust(
+            r#"
+            export interface Node {
+                id: string;
+            }
+
+            export declare class Tree {
+                static isNode(x: any): x is Node;
+                contains(x: any): x is Node;
+            }
+
+            export interface NodeGuard {
+                (x: any): x is Node;
+                description: string;
+            }
+        "#,
+        )?;
+        let rust = rust.replace(" ", "");
+
+        assert!(rust.contains(
+            "pubfnis_node_and_cast(x:JsValue)->std::result::Result<Option<Node>,JsValue>"
+        ));
+        assert!(rust.contains("Self::is_node(x)?"));
+        assert!(rust.contains(
+            "pubfncontains_and_cast(&self,x:JsValue)->std::result::Result<Option<Node>,JsValue>"
+        ));
+        assert!(rust.contains("self.contains(x)?"));
+        assert!(rust.contains(
+            "pubfncall_and_cast(&self,x:JsValue)->std::result::Result<Option<Node>,JsValue>"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_template_literal_types() -> Result<(), Error> {
+        let rust = ts_to_rust(
+            r#"
+            export type Align = `${"top" | "bottom"}-${"left" | "right"}`;
+
+            export type Size = `${number}px`;
+
+            export function setColor(color: `#${string}`): void;
+        "#,
+        )?;
+        let rust = rust.replace(" ", "");
+
+        assert!(rust.contains(r#"#[serde(rename="top-left")]"#));
+        assert!(rust.contains(r#"#[serde(rename="bottom-right")]"#));
+        assert!(rust.contains("pubstructSizeAliased(String);"));
+        assert!(rust.contains(
+            "TemplatePart::Number,ts_bindgen_rt::template_literal::TemplatePart::Literal(\"px\")"
+        ));
+        assert!(rust.contains(
+            r##"TemplatePart::Literal("#"),ts_bindgen_rt::template_literal::TemplatePart::String"##
+        ));
+        assert!(rust.contains("implstd::convert::TryFrom<String>forSizeAliased"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_bigint_mapping() -> Result<(), Error> {
+        let code = r#"
+            export interface Account {
+                balance: bigint;
+                limit?: bigint;
+                history: bigint[];
+            }
+
+            export type Flags = 1n | 2n;
+
+            export declare function total(accounts: Account[]): bigint;
+
+            export declare function each(cb: (n: bigint) => void): void;
+        "#;
+        let to_rust = |bigint_mapping| {
+            let mut fs: MemFs = Default::default();
+            fs.set_cwd(Path::new("/"));
+            fs.add_file_at(Path::new("/test.d.ts"), code.to_string());
+            generate_rust_for_typescript_with_options(
+                fs,
+                "/test",
+                GenerateOptions {
+                    bigint_mapping,
+                    ..Default::default()
+                },
+                |_| {},
+            )
+            .map(|rust| rust.to_string().replace(" ", ""))
+        };
+
+        let rust = ts_to_rust(code)?.replace(" ", "");
+        assert!(rust.contains("pubbalance:js_sys::BigInt"));
+        assert!(rust.contains("pubtypeFlags=js_sys::BigInt;"));
+        assert!(rust.contains("serialize_with=\"ts_bindgen_rt::serialize_jsvalue\""));
+
+        let rust = to_rust(BigIntMapping::I64)?;
+        assert!(rust.contains("pubbalance:i64"));
+        assert!(rust.contains("pubhistory:Vec<i64>"));
+        assert!(rust.contains("serialize_with=\"ts_bindgen_rt::serialize_as_bigint\""));
+        assert!(rust.contains("serialize_with=\"ts_bindgen_rt::serialize_optional_as_bigint\""));
+        assert!(rust.contains("pubtypeFlags=i64;"));
+        assert!(rust.contains("->std::result::Result<i64,JsValue>"));
+        assert!(rust.contains("dynFn(i64)"));
+        assert!(!rust.contains("js_sys::BigInt"));
+
+        let rust = to_rust(BigIntMapping::I128)?;
+        assert!(rust.contains("pubbalance:i128"));
+        assert!(rust.contains("->std::result::Result<i128,JsValue>"));
+        Ok(())
+    }
 }
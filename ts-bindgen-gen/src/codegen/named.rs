@@ -5,7 +5,9 @@ use crate::fs::Fs;
 use crate::identifier::{
     make_identifier, to_camel_case_ident, to_ident, to_snake_case_ident, Identifier,
 };
-use crate::ir::{Builtin, Func, TargetEnrichedTypeInfo, TypeIdent, TypeRef};
+use crate::ir::{
+    BigIntMapping, Builtin, Func, TargetEnrichedTypeInfo, TypeIdent, TypeOptions, TypeRef,
+};
 use crate::mod_def::ToModPathIter;
 use quote::{quote, ToTokens};
 
@@ -26,49 +28,61 @@ pub trait Named {
     ) -> (&str, Identifier);
 }
 
-impl Named for Builtin {
-    fn to_name(&self) -> (&str, Identifier) {
-        // TODO: anything that returns JsValue here should have SerializationType::JsValue
-        match self {
-            Builtin::PrimitiveAny => ("JsValue", to_ident("JsValue")),
-            Builtin::PrimitiveNumber => ("f64", to_ident("f64")),
-            // TODO: make a wrapper in rt to allow objects to be null or undefined
-            Builtin::PrimitiveObject => ("JsValue", to_ident("JsValue")),
-            Builtin::PrimitiveBoolean => ("bool", to_ident("bool")),
-            Builtin::PrimitiveBigInt => ("u64", to_ident("u64")),
-            Builtin::PrimitiveString => ("String", to_ident("String")),
-            // TODO: is this correct?
-            Builtin::PrimitiveVoid => ("()", to_ident("()")),
-            Builtin::PrimitiveUndefined => (
-                "ts_bindgen_rt::Undefined",
-                make_identifier!(ts_bindgen_rt::Undefined),
-            ),
-            Builtin::PrimitiveNull => {
-                ("ts_bindgen_rt::Null", make_identifier!(ts_bindgen_rt::Null))
-            }
-            Builtin::LitNumber => Builtin::PrimitiveNumber.to_name(),
-            Builtin::LitBoolean => Builtin::PrimitiveBoolean.to_name(),
-            Builtin::LitString => Builtin::PrimitiveString.to_name(),
-            Builtin::Promise => ("js_sys::Promise", make_identifier!(js_sys::Promise)),
-            Builtin::Array => ("Vec", to_ident("Vec")),
-            Builtin::Fn => ("Fn", to_ident("Fn")),
-            Builtin::Map => (
-                "std::collections::HashMap<String, JsValue>",
-                make_identifier!(std::collections::HashMap<String, JsValue>),
-            ),
-            Builtin::Optional => ("Option", to_ident("Option")),
-            Builtin::Variadic => ("", to_ident("")),
-            Builtin::Named(n) => (n, Identifier::with_path(n)),
-        }
+/// Returns the js name and rust identifier for `builtin` as represented
+/// according to `options`
+fn builtin_name(builtin: &Builtin, options: TypeOptions) -> (&str, Identifier) {
+    // TODO: anything that returns JsValue here should have SerializationType::JsValue
+    match builtin {
+        Builtin::PrimitiveAny => ("JsValue", to_ident("JsValue")),
+        Builtin::PrimitiveNumber => ("f64", to_ident("f64")),
+        // TODO: make a wrapper in rt to allow objects to be null or undefined
+        Builtin::PrimitiveObject => ("JsValue", to_ident("JsValue")),
+        Builtin::PrimitiveBoolean => ("bool", to_ident("bool")),
+        Builtin::PrimitiveBigInt => bigint_name(options.bigint_mapping),
+        Builtin::PrimitiveString => ("String", to_ident("String")),
+        // TODO: is this correct?
+        Builtin::PrimitiveVoid => ("()", to_ident("()")),
+        Builtin::PrimitiveUndefined => (
+            "ts_bindgen_rt::Undefined",
+            make_identifier!(ts_bindgen_rt::Undefined),
+        ),
+        Builtin::PrimitiveNull => ("ts_bindgen_rt::Null", make_identifier!(ts_bindgen_rt::Null)),
+        // classes and wrapped interfaces substitute their own wrapper type
+        // (and their traits substitute `Self`) before rendering. anywhere
+        // else, e.g. serde struct fields, naming the enclosing type could
+        // make it infinitely sized so we fall back to a plain js value.
+        // we fully qualify it to keep it distinct from `any` in type envs.
+        Builtin::PolymorphicThis => ("this", make_identifier!(wasm_bindgen::JsValue)),
+        Builtin::LitNumber => builtin_name(&Builtin::PrimitiveNumber, options),
+        Builtin::LitBoolean => builtin_name(&Builtin::PrimitiveBoolean, options),
+        Builtin::LitString => builtin_name(&Builtin::PrimitiveString, options),
+        Builtin::Promise => ("js_sys::Promise", make_identifier!(js_sys::Promise)),
+        // predicates are lowered to booleans during target enrichment
+        Builtin::TypePredicate(_) => builtin_name(&Builtin::PrimitiveBoolean, options),
+        Builtin::AsyncIterable if options.async_iter_streams => (
+            "ts_bindgen_rt::AsyncIterStream",
+            make_identifier!(ts_bindgen_rt::AsyncIterStream),
+        ),
+        Builtin::AsyncIterable => ("JsValue", to_ident("JsValue")),
+        Builtin::Array | Builtin::ReadonlyArray => ("Vec", to_ident("Vec")),
+        Builtin::Fn => ("Fn", to_ident("Fn")),
+        Builtin::Map => (
+            "std::collections::HashMap<String, JsValue>",
+            make_identifier!(std::collections::HashMap<String, JsValue>),
+        ),
+        Builtin::Optional => ("Option", to_ident("Option")),
+        Builtin::Variadic => ("", to_ident("")),
+        Builtin::Named(n) => (n, Identifier::with_path(n)),
     }
+}
 
-    fn to_rel_qualified_name<FS: Fs + ?Sized, T: ToModPathIter>(
-        &self,
-        _fs: &FS,
-        _ns_base: &T,
-    ) -> (&str, Identifier) {
-        // builtins are global - no namespacing required
-        self.to_name()
+/// Returns the js name and rust identifier of the type we represent bigints as
+fn bigint_name(mapping: BigIntMapping) -> (&'static str, Identifier) {
+    match mapping {
+        BigIntMapping::JsBigInt => ("js_sys::BigInt", make_identifier!(js_sys::BigInt)),
+        BigIntMapping::I64 => ("i64", to_ident("i64")),
+        BigIntMapping::U64 => ("u64", to_ident("u64")),
+        BigIntMapping::I128 => ("i128", to_ident("i128")),
     }
 }
 
@@ -79,11 +93,13 @@ impl Named for Builtin {
 pub struct CasedTypeIdent<'a> {
     pub type_ident: &'a TypeIdent,
     pub type_info: &'a TargetEnrichedTypeInfo,
+    pub type_options: TypeOptions,
 }
 
 fn name_for_type_ident_and_info<'a>(
     type_ident: &'a TypeIdent,
     type_info: &TargetEnrichedTypeInfo,
+    type_options: TypeOptions,
     ns: &[Identifier],
 ) -> (&'a str, Identifier) {
     let target_type = type_info.resolve_target_type();
@@ -92,7 +108,7 @@ fn name_for_type_ident_and_info<'a>(
         .map(casing_for_type)
         .unwrap_or(&to_camel_case_ident);
     match type_ident {
-        TypeIdent::Builtin(builtin) => builtin.to_name(),
+        TypeIdent::Builtin(builtin) => builtin_name(builtin, type_options),
         TypeIdent::GeneratedName { .. } => {
             panic!("expected all generated names to be resolved")
         }
@@ -111,7 +127,7 @@ fn name_for_type_ident_and_info<'a>(
 
 impl<'a> Named for CasedTypeIdent<'a> {
     fn to_name(&self) -> (&str, Identifier) {
-        name_for_type_ident_and_info(self.type_ident, self.type_info, &[])
+        name_for_type_ident_and_info(self.type_ident, self.type_info, self.type_options, &[])
     }
 
     fn to_rel_qualified_name<FS: Fs + ?Sized, T: ToModPathIter>(
@@ -120,7 +136,7 @@ impl<'a> Named for CasedTypeIdent<'a> {
         ns_base: &T,
     ) -> (&str, Identifier) {
         let ns = self.type_ident.to_ns_path(fs, ns_base);
-        name_for_type_ident_and_info(self.type_ident, self.type_info, &ns)
+        name_for_type_ident_and_info(self.type_ident, self.type_info, self.type_options, &ns)
     }
 }
 
@@ -129,12 +145,16 @@ impl<'a> Named for CasedTypeIdent<'a> {
 fn retain_target_type_params(type_ref: &TypeRef, id: &Identifier) -> bool {
     // TODO: this is ugly...
     // we keep HashMap<String, JsValue> but maybe should keep other type params?
-    !id.type_params.is_empty()
-        && matches!(&type_ref.referent, TypeIdent::Builtin(_))
-        && !matches!(
-            &type_ref.referent,
-            TypeIdent::Builtin(Builtin::Fn | Builtin::Map)
-        )
+    // async iterables without streams are rendered as a bare JsValue, dropping
+    // the item type
+    (type_ref.referent == TypeIdent::Builtin(Builtin::AsyncIterable)
+        && !type_ref.context.type_options.async_iter_streams)
+        || (!id.type_params.is_empty()
+            && matches!(&type_ref.referent, TypeIdent::Builtin(_))
+            && !matches!(
+                &type_ref.referent,
+                TypeIdent::Builtin(Builtin::Fn | Builtin::Map)
+            ))
 }
 
 fn name_for_type_ref<'a>(tr: &'a TypeRef, ns: &[Identifier]) -> (&'a str, Identifier) {
@@ -142,7 +162,7 @@ fn name_for_type_ref<'a>(tr: &'a TypeRef, ns: &[Identifier]) -> (&'a str, Identi
     let (n, mut id) = target_type
         // we pass an empty namespace to name_for_type_ident_and_info
         // so we can namespace_for_ident later
-        .map(|t| name_for_type_ident_and_info(&tr.referent, &t, &[]))
+        .map(|t| name_for_type_ident_and_info(&tr.referent, &t, tr.context.type_options, &[]))
         .unwrap_or_else(|| {
             let js_name = tr.referent.js_name();
             (js_name, to_camel_case_ident(js_name))
@@ -185,16 +205,12 @@ trait JsName {
     fn js_name(&self) -> &str;
 }
 
-impl JsName for Builtin {
-    fn js_name(&self) -> &str {
-        self.to_name().0
-    }
-}
-
 impl JsName for TypeIdent {
     fn js_name(&self) -> &str {
         match self {
-            TypeIdent::Builtin(builtin) => builtin.js_name(),
+            TypeIdent::Builtin(_) => {
+                panic!("expected builtins to resolve to themselves")
+            }
             TypeIdent::GeneratedName { .. } => {
                 panic!("expected all generated names to be resolved")
             }
@@ -227,7 +243,9 @@ impl SimpleNamed for TypeRef {
 
 fn casing_for_type<T: AsRef<str>>(typ: &TargetEnrichedTypeInfo) -> &dyn Fn(T) -> Identifier {
     match typ {
-        TargetEnrichedTypeInfo::FuncGroup(_) => &to_snake_case_ident as &dyn Fn(T) -> Identifier,
+        TargetEnrichedTypeInfo::FuncGroup(_) | TargetEnrichedTypeInfo::Var { .. } => {
+            &to_snake_case_ident as &dyn Fn(T) -> Identifier
+        }
         TargetEnrichedTypeInfo::Ref(tr)
             if matches!(&tr.referent, TypeIdent::Builtin(Builtin::Fn)) =>
         {
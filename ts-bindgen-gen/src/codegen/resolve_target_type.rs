@@ -110,6 +110,8 @@ impl ResolveTargetType for NamespaceImport {
                 // TODO
                 None
             }
+            // namespaces aren't types
+            NamespaceImport::Namespace { .. } => None,
         }
     }
 }
@@ -1,6 +1,7 @@
+use crate::codegen::is_wrapper::IsWrapper;
 use crate::codegen::resolve_target_type::ResolveTargetType;
 use crate::codegen::type_ref_like::{OwnedTypeRef, TypeRefLike};
-use crate::ir::{Builtin, TargetEnrichedTypeInfo, TypeIdent, TypeRef, Union};
+use crate::ir::{BigIntMapping, Builtin, TargetEnrichedTypeInfo, Tuple, TypeIdent, TypeRef, Union};
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
 
@@ -31,7 +32,24 @@ impl SerializationTypeGetter for TargetEnrichedTypeInfo {
         match self {
             TargetEnrichedTypeInfo::FuncGroup(_) => SerializationType::Fn,
             TargetEnrichedTypeInfo::Class(_) => SerializationType::Raw,
-            TargetEnrichedTypeInfo::Tuple(_) => SerializationType::Array,
+            TargetEnrichedTypeInfo::Interface(iface) if iface.is_wrapper() => {
+                SerializationType::Raw
+            }
+            // optional and rest elements don't map onto a fixed set of array slots
+            // so we let their serde implementations handle them
+            TargetEnrichedTypeInfo::Tuple(Tuple {
+                types, has_rest, ..
+            }) => {
+                if *has_rest
+                    || types
+                        .iter()
+                        .any(|t| t.referent == TypeIdent::Builtin(Builtin::Optional))
+                {
+                    SerializationType::SerdeJson
+                } else {
+                    SerializationType::Array
+                }
+            }
             TargetEnrichedTypeInfo::Union(Union { types, .. }) => {
                 if types
                     .iter()
@@ -44,10 +62,28 @@ impl SerializationTypeGetter for TargetEnrichedTypeInfo {
             }
             TargetEnrichedTypeInfo::Ref(t) => match &t.referent {
                 TypeIdent::Builtin(Builtin::Fn) => SerializationType::Fn,
-                TypeIdent::Builtin(Builtin::Array) => SerializationType::Array,
+                TypeIdent::Builtin(Builtin::Array | Builtin::ReadonlyArray) => {
+                    SerializationType::Array
+                }
                 TypeIdent::Builtin(
-                    Builtin::PrimitiveAny | Builtin::PrimitiveObject | Builtin::Named(_),
+                    Builtin::PrimitiveAny
+                    | Builtin::PrimitiveObject
+                    | Builtin::PolymorphicThis
+                    | Builtin::Named(_),
                 ) => SerializationType::JsValue,
+                TypeIdent::Builtin(Builtin::AsyncIterable)
+                    if !t.context.type_options.async_iter_streams =>
+                {
+                    SerializationType::JsValue
+                }
+                // wasm-bindgen can't pass i128s so they go through our serializer
+                TypeIdent::Builtin(Builtin::PrimitiveBigInt) => {
+                    match t.context.type_options.bigint_mapping {
+                        BigIntMapping::JsBigInt => SerializationType::JsValue,
+                        BigIntMapping::I64 | BigIntMapping::U64 => SerializationType::Raw,
+                        BigIntMapping::I128 => SerializationType::SerdeJson,
+                    }
+                }
                 TypeIdent::Builtin(_) => SerializationType::Raw,
                 _ => SerializationType::SerdeJson,
             },
@@ -72,6 +108,30 @@ impl<'a> SerializationTypeGetter for TypeRefLike<'a> {
     }
 }
 
+/// Returns the ts_bindgen_rt function to serialize `typ` with if it is a bigint
+/// that we represent as a rust integer, which would otherwise serialize as a number
+pub fn bigint_serialize_with(typ: &TypeRef) -> Option<&'static str> {
+    let is_integer_bigint = |typ: &TypeRef| {
+        matches!(
+            typ.resolve_target_type(),
+            Some(TargetEnrichedTypeInfo::Ref(t))
+                if t.referent == TypeIdent::Builtin(Builtin::PrimitiveBigInt)
+                    && t.context.type_options.bigint_mapping != BigIntMapping::JsBigInt
+        )
+    };
+
+    if typ.referent == TypeIdent::Builtin(Builtin::Optional) {
+        typ.type_params
+            .first()
+            .filter(|t| is_integer_bigint(t))
+            .map(|_| "ts_bindgen_rt::serialize_optional_as_bigint")
+    } else if is_integer_bigint(typ) {
+        Some("ts_bindgen_rt::serialize_as_bigint")
+    } else {
+        None
+    }
+}
+
 trait IsCopy {
     fn is_copy(&self) -> bool;
 }
@@ -88,7 +148,8 @@ impl IsCopy for TargetEnrichedTypeInfo {
                             | Builtin::LitNumber
                             | Builtin::LitBoolean
                     )
-                )
+                ) || (t.referent == TypeIdent::Builtin(Builtin::PrimitiveBigInt)
+                    && t.context.type_options.bigint_mapping != BigIntMapping::JsBigInt)
             }
             _ => false,
         }
@@ -1,16 +1,22 @@
-use crate::codegen::funcs::{AccessType, FnPrototypeExt, PropertyAccessor};
-use crate::codegen::generics::{render_type_params, render_type_params_with_constraints};
-use crate::codegen::generics::{ResolveGeneric, TypeEnvImplying};
-use crate::codegen::named::{FnOverloadName, Named};
+use crate::codegen::funcs::{
+    AccessType, FnPrototypeExt, HasFnPrototype, ParamExt, PropertyAccessor,
+};
+use crate::codegen::generics::{
+    render_type_params, render_type_params_with_constraints, render_type_params_with_defaults,
+};
+use crate::codegen::generics::{ResolveGeneric, TypeEnvImplying, POLYMORPHIC_THIS_KEY};
+use crate::codegen::is_wrapper::IsWrapper;
+use crate::codegen::named::{FnOverloadName, Named, SimpleNamed};
 use crate::codegen::resolve_target_type::ResolveTargetType;
 use crate::codegen::serialization_type::clone_item_of_type;
 use crate::identifier::{to_snake_case_ident, Identifier};
 use crate::ir::{
-    Builtin, Class, Context, Func, Interface, Intersection, Member, TargetEnrichedTypeInfo,
-    TypeIdent, TypeParamConfig, TypeRef,
+    Builtin, Class, Context, Func, Interface, Intersection, Member, Property,
+    TargetEnrichedTypeInfo, TypeIdent, TypeParamConfig, TypeRef,
 };
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{format_ident, quote};
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 use std::iter;
 
@@ -46,6 +52,48 @@ impl TraitMember {
             TraitMember::Setter { .. } => true,
         }
     }
+
+    /// Renders our declaration within a trait, where polymorphic `this` is
+    /// rendered as `Self`.
+    fn trait_decl(&self, ctx: &Context) -> TokenStream2 {
+        let this_env: HashMap<_, _> = iter::once((
+            POLYMORPHIC_THIS_KEY.to_string(),
+            TypeRef {
+                referent: TypeIdent::ExactName("Self".to_string()),
+                type_params: Default::default(),
+                context: ctx.clone(),
+            },
+        ))
+        .collect();
+        self.resolve_generic_in_env(&this_env)
+            .exposed_to_rust_fn_decl(self.name(), self.is_fallible(), Some(ctx))
+    }
+}
+
+impl ResolveGeneric for TraitMember {
+    fn resolve_generic_in_env<'a>(
+        &'a self,
+        type_env: &'a HashMap<String, TypeRef>,
+    ) -> Cow<'a, Self> {
+        let resolve_prop = |prop: &PropertyAccessor| PropertyAccessor {
+            typ: prop.typ.resolve_generic_in_env(type_env).into_owned(),
+            ..prop.clone()
+        };
+        Cow::Owned(match self {
+            TraitMember::Method { name, method } => TraitMember::Method {
+                name: name.clone(),
+                method: method.resolve_generic_in_env(type_env).into_owned(),
+            },
+            TraitMember::Getter { name, prop } => TraitMember::Getter {
+                name: name.clone(),
+                prop: resolve_prop(prop),
+            },
+            TraitMember::Setter { name, prop } => TraitMember::Setter {
+                name: name.clone(),
+                prop: resolve_prop(prop),
+            },
+        })
+    }
 }
 
 pub fn to_type_ref(
@@ -79,6 +127,7 @@ where
     T: Traitable,
 {
     let tps = render_type_params(type_params);
+    let tps_with_defaults = render_type_params_with_defaults(type_params);
     let full_name = quote! {
         #name #tps
     };
@@ -96,6 +145,9 @@ where
         let name = to_snake_case_ident(&n);
         match m {
             Member::Constructor(_) => Default::default(),
+            // statics belong to the class rather than its instances
+            Member::StaticMethod(_) | Member::StaticProperty(_) => Default::default(),
+            Member::Iterator(_) | Member::AsyncIterator(_) => Default::default(),
             Member::Method(f) => {
                 let is_overloaded = f.overloads.len() > 1;
                 f.overloads
@@ -111,13 +163,21 @@ where
                     })
                     .collect()
             }
-            Member::Property(t) => {
+            Member::Property(Property {
+                type_info: t,
+                readonly,
+                ..
+            }) => {
                 let getter = PropertyAccessor {
                     property_name: name.clone(),
                     typ: t.resolve_generic_in_env(type_env).into_owned(),
                     class_name: class_name(),
                     access_type: AccessType::Getter,
                 };
+                if readonly {
+                    return vec![TraitMember::Getter { name, prop: getter }];
+                }
+
                 let setter = PropertyAccessor {
                     property_name: name.clone(),
                     typ: t.resolve_generic_in_env(type_env).into_owned(),
@@ -164,11 +224,7 @@ where
                 .methods()
                 .flat_map(|(n, m)| member_to_trait_member(&tr.type_env(), (n, m)).into_iter())
                 .map(|trait_member| {
-                    let proto = trait_member.exposed_to_rust_fn_decl(
-                        trait_member.name(),
-                        trait_member.is_fallible(),
-                        Some(ctx),
-                    );
+                    let proto = trait_member.trait_decl(ctx);
                     let imp = item.wrap_invocation(&i.implementor, &trait_member, ctx);
                     quote! {
                         #proto {
@@ -197,7 +253,7 @@ where
     let method_decls = item
         .methods()
         .flat_map(|nm| member_to_trait_member(&Default::default(), nm))
-        .map(|f| f.exposed_to_rust_fn_decl(f.name(), f.is_fallible(), Some(ctx)))
+        .map(|f| f.trait_decl(ctx))
         .map(|t| {
             quote! {
                 #t;
@@ -214,7 +270,7 @@ where
 
     quote! {
         #[allow(non_camel_case_types, non_snake_case)]
-        #vis trait #trait_name #tps #super_decl {
+        #vis trait #trait_name #tps_with_defaults #super_decl {
             #(#method_decls)*
         }
 
@@ -316,11 +372,16 @@ impl Traitable for Interface {
     }
 
     fn methods(&self) -> BoxedMemberIter<'_> {
-        Box::new(
-            self.fields
-                .iter()
-                .map(|(n, t)| (n.clone(), Member::Property(t.clone()))),
-        )
+        Box::new(self.fields.iter().map(move |(n, t)| {
+            (
+                n.clone(),
+                Member::Property(Property {
+                    readonly: self.readonly_fields.contains(n),
+                    type_info: t.clone(),
+                    context: t.context.clone(),
+                }),
+            )
+        }))
     }
 
     fn contains_implementation(&self) -> bool {
@@ -345,6 +406,43 @@ impl Traitable for Interface {
             TraitMember::Method { method, .. } => {
                 method.fully_qualified_invoke_with_name(fq_name, Some(slf))
             }
+            // callable and iterable interfaces wrap js objects so their
+            // properties are accessed through js rather than stored on a rust
+            // struct
+            TraitMember::Getter { prop, .. } if self.is_wrapper() => cast_this_result(
+                trait_member,
+                prop.getter_fn()
+                    .fully_qualified_invoke_with_name(fq_name, Some(slf)),
+            ),
+            TraitMember::Setter { prop, .. } if self.is_wrapper() => {
+                let this_args = cast_this_args(trait_member);
+                let inv = prop
+                    .setter_fn()
+                    .fully_qualified_invoke_with_name(fq_name, Some(slf));
+                quote! {
+                    #this_args
+                    #inv
+                }
+            }
+            // a struct can't contain itself so its polymorphic `this` fields
+            // hold js values that we convert to and from `Self`
+            TraitMember::Getter { prop, .. } if is_polymorphic_this(&prop.typ) => {
+                let property_name = &prop.property_name;
+                quote! {
+                    ts_bindgen_rt::from_jsvalue(&self.#property_name)
+                        .map_err(ts_bindgen_rt::Error::from)
+                        .map_err(JsValue::from)
+                }
+            }
+            TraitMember::Setter { prop, .. } if is_polymorphic_this(&prop.typ) => {
+                let property_name = &prop.property_name;
+                quote! {
+                    self.#property_name = ts_bindgen_rt::to_jsvalue(&value)
+                        .map_err(ts_bindgen_rt::Error::from)
+                        .map_err(JsValue::from)?;
+                    Ok(())
+                }
+            }
             TraitMember::Getter { prop, .. } => {
                 let property_name = &prop.property_name;
                 let typ = prop.typ.resolve_target_type().unwrap_or_else(|| {
@@ -388,7 +486,11 @@ impl Traitable for Class {
     }
 
     fn methods(&self) -> BoxedMemberIter<'_> {
-        Box::new(self.members.iter().map(|(n, m)| (n.clone(), m.clone())))
+        Box::new(
+            self.members
+                .iter()
+                .map(|(key, m)| (key.js_name().to_string(), m.clone())),
+        )
     }
 
     fn contains_implementation(&self) -> bool {
@@ -440,9 +542,15 @@ impl Traitable for Class {
                 let #tgt_mut target: #member_defn_source = #slf.into();
             }
         };
+        let this_args = cast_this_args(trait_member);
+        let conv = quote! {
+            #conv
+            #this_args
+        };
+        let cast_result = |inv: TokenStream2| cast_this_result(trait_member, inv);
         match trait_member {
             TraitMember::Method { method, .. } => {
-                let inv = method.fully_qualified_invoke_with_name(name, Some(&target));
+                let inv = cast_result(method.fully_qualified_invoke_with_name(name, Some(&target)));
                 quote! {
                     #conv
                     #inv
@@ -451,7 +559,7 @@ impl Traitable for Class {
             TraitMember::Getter { prop, .. } => {
                 let f = prop.getter_fn();
 
-                let inv = f.fully_qualified_invoke_with_name(name, Some(&target));
+                let inv = cast_result(f.fully_qualified_invoke_with_name(name, Some(&target)));
                 quote! {
                     #conv
                     #inv
@@ -469,6 +577,40 @@ impl Traitable for Class {
     }
 }
 
+fn is_polymorphic_this(typ: &TypeRef) -> bool {
+    matches!(&typ.referent, TypeIdent::Builtin(Builtin::PolymorphicThis))
+}
+
+/// Polymorphic `this` is `Self` or a js value in a trait but the wrapper type
+/// of the implementing item in its own methods so we cast arguments between
+/// them.
+fn cast_this_args(trait_member: &TraitMember) -> TokenStream2 {
+    let this_args = trait_member
+        .args()
+        .filter(|p| is_polymorphic_this(p.type_ref().as_ref()))
+        .map(|p| {
+            let n = p.rust_name();
+            quote! {
+                let #n = wasm_bindgen::JsCast::unchecked_into(#n);
+            }
+        });
+    quote! {
+        #(#this_args)*
+    }
+}
+
+/// Casts the result of `inv` from the wrapper type of the implementing item
+/// to the trait's representation of polymorphic `this`, if necessary.
+fn cast_this_result(trait_member: &TraitMember, inv: TokenStream2) -> TokenStream2 {
+    if is_polymorphic_this(&trait_member.return_type()) {
+        quote! {
+            #inv.map(wasm_bindgen::JsCast::unchecked_into)
+        }
+    } else {
+        inv
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -494,7 +636,7 @@ mod test {
 
         let tbnbf = TsTypes::parse(arc_fs.clone(), &test_path.to_string_lossy()).unwrap();
 
-        let tbnbf = to_final_ir(tbnbf, arc_fs);
+        let tbnbf = to_final_ir(tbnbf, &Default::default(), Default::default(), arc_fs);
         let tbnbf = tbnbf.borrow();
         let types = tbnbf.get(test_path).unwrap();
 
@@ -553,7 +695,7 @@ mod test {
 
         let tbnbf = TsTypes::parse(arc_fs.clone(), &test_path.to_string_lossy()).unwrap();
 
-        let tbnbf = to_final_ir(tbnbf, arc_fs);
+        let tbnbf = to_final_ir(tbnbf, &Default::default(), Default::default(), arc_fs);
         let tbnbf = tbnbf.borrow();
         let types = tbnbf.get(test_path).unwrap();
 
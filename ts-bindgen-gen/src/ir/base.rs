@@ -63,6 +63,7 @@ impl TypeName {
 pub enum EnumValue {
     Str(String),
     Num(f64),
+    Bool(bool),
 }
 
 impl Eq for EnumValue {}
@@ -94,6 +95,14 @@ impl Param {
             is_variadic: self.is_variadic,
         }
     }
+
+    fn substitute_type_param(&self, name: &str, replacement: &TypeInfo) -> Self {
+        Param {
+            name: self.name.clone(),
+            type_info: self.type_info.substitute_type_param(name, replacement),
+            is_variadic: self.is_variadic,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -115,6 +124,16 @@ impl FuncGroup {
                 .collect(),
         }
     }
+
+    fn substitute_type_param(&self, name: &str, replacement: &TypeInfo) -> Self {
+        FuncGroup {
+            overloads: self
+                .overloads
+                .iter()
+                .map(|o| o.substitute_type_param(name, replacement))
+                .collect(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -146,6 +165,24 @@ impl Func {
             class_name: self.class_name.clone(),
         }
     }
+
+    fn substitute_type_param(&self, name: &str, replacement: &TypeInfo) -> Self {
+        // our own type params shadow `name`
+        if self.type_params.iter().any(|(n, _)| n == name) {
+            return self.clone();
+        }
+
+        Func {
+            type_params: self.type_params.clone(),
+            params: self
+                .params
+                .iter()
+                .map(|p| p.substitute_type_param(name, replacement))
+                .collect(),
+            return_type: Box::new(self.return_type.substitute_type_param(name, replacement)),
+            class_name: self.class_name.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -188,13 +225,29 @@ impl Ctor {
                 .collect(),
         }
     }
+
+    fn substitute_type_param(&self, name: &str, replacement: &TypeInfo) -> Self {
+        Ctor {
+            params: self
+                .params
+                .iter()
+                .map(|p| p.substitute_type_param(name, replacement))
+                .collect(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Member {
     Constructor(CtorGroup),
     Method(FuncGroup),
-    Property(TypeInfo),
+    Property(Property),
+    StaticMethod(FuncGroup),
+    StaticProperty(Property),
+    /// `[Symbol.iterator]()`, yielding items of the given type
+    Iterator(TypeInfo),
+    /// `[Symbol.asyncIterator]()`, yielding items of the given type
+    AsyncIterator(TypeInfo),
 }
 
 impl Member {
@@ -208,9 +261,70 @@ impl Member {
                 Self::Constructor(ctor.resolve_names(types_by_name_by_file, type_params))
             }
             Self::Method(f) => Self::Method(f.resolve_names(types_by_name_by_file, type_params)),
-            Self::Property(t) => {
-                Self::Property(t.resolve_names(types_by_name_by_file, type_params))
+            Self::Property(p) => {
+                Self::Property(p.resolve_names(types_by_name_by_file, type_params))
+            }
+            Self::StaticMethod(f) => {
+                Self::StaticMethod(f.resolve_names(types_by_name_by_file, type_params))
             }
+            Self::StaticProperty(p) => {
+                Self::StaticProperty(p.resolve_names(types_by_name_by_file, type_params))
+            }
+            Self::Iterator(t) => {
+                Self::Iterator(t.resolve_names(types_by_name_by_file, type_params))
+            }
+            Self::AsyncIterator(t) => {
+                Self::AsyncIterator(t.resolve_names(types_by_name_by_file, type_params))
+            }
+        }
+    }
+}
+
+/// The key of a class member.
+///
+/// A class may have a static and an instance member with the same name so we
+/// key statics apart from instance members.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MemberKey {
+    Instance(String),
+    Static(String),
+}
+
+impl MemberKey {
+    /// Returns the js name of the member.
+    pub fn js_name(&self) -> &str {
+        match self {
+            MemberKey::Instance(name) | MemberKey::Static(name) => name,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Property {
+    pub readonly: bool,
+    pub type_info: TypeInfo,
+}
+
+impl Property {
+    fn resolve_names(
+        &self,
+        types_by_name_by_file: &HashMap<PathBuf, HashMap<TypeIdent, Type>>,
+        type_params: &HashMap<String, TypeParamConfig>,
+    ) -> Self {
+        Property {
+            readonly: self.readonly,
+            type_info: self
+                .type_info
+                .resolve_names(types_by_name_by_file, type_params),
+        }
+    }
+}
+
+impl From<TypeInfo> for Property {
+    fn from(type_info: TypeInfo) -> Property {
+        Property {
+            readonly: false,
+            type_info,
         }
     }
 }
@@ -276,9 +390,22 @@ impl TypeRef {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NamespaceImport {
-    Default { src: PathBuf },
-    All { src: PathBuf },
-    Named { src: PathBuf, name: String },
+    Default {
+        src: PathBuf,
+    },
+    All {
+        src: PathBuf,
+    },
+    Named {
+        src: PathBuf,
+        name: String,
+    },
+    /// A binding of the namespace named by `name_parts` in `src`, e.g. from
+    /// `import NS = A.B`
+    Namespace {
+        src: PathBuf,
+        name_parts: Vec<String>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -292,8 +419,16 @@ pub struct Interface {
     pub indexer: Option<Indexer>,
     pub extends: Vec<BaseClass>,
     pub fields: HashMap<String, TypeInfo>,
+    /// Names of the fields declared `readonly`
+    pub readonly_fields: HashSet<String>,
     pub type_params: Vec<(String, TypeParamConfig)>,
     pub constructor: Option<Ctor>, // TODO: make this a ctor group
+    /// Call signatures, for interfaces describing callable objects
+    pub callable: Option<FuncGroup>,
+    /// Item type of `[Symbol.iterator]`, for iterable interfaces
+    pub iterator: Option<Box<TypeInfo>>,
+    /// Item type of `[Symbol.asyncIterator]`, for async iterable interfaces
+    pub async_iterator: Option<Box<TypeInfo>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -324,7 +459,7 @@ impl TypeParamConfig {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Class {
     pub super_class: Option<Box<TypeRef>>,
-    pub members: HashMap<String, Member>,
+    pub members: HashMap<MemberKey, Member>,
     pub type_params: Vec<(String, TypeParamConfig)>,
     pub implements: Vec<TypeRef>,
 }
@@ -339,9 +474,14 @@ pub struct Union {
     pub types: Vec<TypeInfo>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct Tuple {
     pub types: Vec<TypeInfo>,
+    /// Element labels (`[a: string, b?: number]`), empty if the tuple is unlabeled.
+    pub labels: Vec<String>,
+    /// Whether the last element is a rest element (`[string, ...number[]]`), in which case
+    /// the last type is the array type of the rest element.
+    pub has_rest: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -374,6 +514,449 @@ impl Alias {
     }
 }
 
+/// A conditional type, `check_type extends extends_type ? true_type : false_type`.
+/// Conditional types only exist until name resolution, where they are
+/// evaluated into one of their branches (or a union of them if we can't
+/// decide the check).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conditional {
+    pub check_type: Box<TypeInfo>,
+    pub extends_type: Box<TypeInfo>,
+    pub true_type: Box<TypeInfo>,
+    pub false_type: Box<TypeInfo>,
+}
+
+impl Conditional {
+    /// Resolves the branch selected by our check, if we can decide it.
+    fn resolve_decided_branch(
+        &self,
+        types_by_name_by_file: &HashMap<PathBuf, HashMap<TypeIdent, Type>>,
+        type_params: &HashMap<String, TypeParamConfig>,
+    ) -> Option<TypeInfo> {
+        let check_type = self
+            .check_type
+            .resolve_names(types_by_name_by_file, type_params);
+        let extends_type = self
+            .extends_type
+            .resolve_names(types_by_name_by_file, type_params);
+
+        let branch = if is_assignable(
+            types_by_name_by_file,
+            type_params,
+            &check_type,
+            &extends_type,
+            0,
+        )? {
+            &self.true_type
+        } else {
+            &self.false_type
+        };
+        Some(branch.resolve_names(types_by_name_by_file, type_params))
+    }
+
+    fn resolve_names(
+        &self,
+        types_by_name_by_file: &HashMap<PathBuf, HashMap<TypeIdent, Type>>,
+        type_params: &HashMap<String, TypeParamConfig>,
+    ) -> TypeInfo {
+        self.resolve_decided_branch(types_by_name_by_file, type_params)
+            .unwrap_or_else(|| {
+                union_of_branches(
+                    self.true_type
+                        .resolve_names(types_by_name_by_file, type_params),
+                    self.false_type
+                        .resolve_names(types_by_name_by_file, type_params),
+                )
+            })
+    }
+}
+
+/// Evaluates `type_ref` if it references a generic alias of a conditional type
+/// (`type X<T> = T extends string ? A : B` referenced as `X<"a">`), whose check
+/// we can only decide once the alias's type params are replaced by the
+/// arguments of `type_ref`.
+/// Returns `None` if `type_ref` doesn't reference such an alias or the check
+/// remains undecidable.
+fn instantiate_conditional_alias(
+    types_by_name_by_file: &HashMap<PathBuf, HashMap<TypeIdent, Type>>,
+    type_params: &HashMap<String, TypeParamConfig>,
+    type_ref: &TypeRef,
+) -> Option<TypeInfo> {
+    if type_ref.type_params.is_empty() {
+        return None;
+    }
+
+    let alias = match &lookup_type(types_by_name_by_file, &type_ref.referent)?.info {
+        TypeInfo::Alias(alias @ Alias { target, .. })
+            if matches!(target.as_ref(), TypeInfo::Conditional(_)) =>
+        {
+            alias
+        }
+        _ => return None,
+    };
+
+    let mut type_args = type_ref.type_params.iter();
+    let target =
+        alias
+            .type_params
+            .iter()
+            .try_fold((*alias.target).clone(), |target, (name, config)| {
+                // missing type args take the default of their param
+                let type_arg = type_args
+                    .next()
+                    .or_else(|| config.default_type_arg.as_ref())?;
+                Some(target.substitute_type_param(name, type_arg))
+            })?;
+
+    match target {
+        TypeInfo::Conditional(cond) => {
+            cond.resolve_decided_branch(types_by_name_by_file, type_params)
+        }
+        _ => None,
+    }
+}
+
+/// Fallback for a conditional type whose check we could not decide.
+/// If either branch is `any` (e.g. because it referenced an `infer`red
+/// type we could not bind), the whole thing is `any`.
+fn union_of_branches(true_type: TypeInfo, false_type: TypeInfo) -> TypeInfo {
+    match (true_type, false_type) {
+        (TypeInfo::PrimitiveAny(_), _) | (_, TypeInfo::PrimitiveAny(_)) => {
+            TypeInfo::PrimitiveAny(PrimitiveAny())
+        }
+        (true_type, false_type) => {
+            union_of_distinct([true_type, false_type].into_iter().flat_map(|t| match t {
+                TypeInfo::Union(Union { types }) => types,
+                t => vec![t],
+            }))
+        }
+    }
+}
+
+/// Modifier applied to the properties of a mapped type, e.g. the `-?` in
+/// `{ [K in keyof T]-?: T[K] }`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MappedModifier {
+    Add,
+    Remove,
+}
+
+/// A mapped type, `{ [key_name in key_type]: value_type }`.
+/// Like conditional types, mapped types only exist until name resolution,
+/// where they are expanded into an interface if we know the full set of
+/// keys or into a map otherwise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MappedType {
+    pub key_name: String,
+    pub key_type: Box<TypeInfo>,
+    pub value_type: Box<TypeInfo>,
+    pub optional: Option<MappedModifier>,
+    pub readonly: Option<MappedModifier>,
+}
+
+impl MappedType {
+    fn resolve_names(
+        &self,
+        types_by_name_by_file: &HashMap<PathBuf, HashMap<TypeIdent, Type>>,
+        type_params: &HashMap<String, TypeParamConfig>,
+    ) -> TypeInfo {
+        let keys = match mapped_keys(types_by_name_by_file, type_params, &self.key_type) {
+            Some(keys) => keys,
+            None => {
+                // open set of keys, the best we can do is a map from strings to values
+                return TypeInfo::Mapped {
+                    value_type: Box::new(
+                        self.value_type
+                            .substitute_type_param(
+                                &self.key_name,
+                                &TypeInfo::PrimitiveString(PrimitiveString()),
+                            )
+                            .resolve_names(types_by_name_by_file, type_params),
+                    ),
+                };
+            }
+        };
+
+        let readonly_fields = match self.readonly {
+            Some(MappedModifier::Add) => keys.iter().map(|(key, _)| key.clone()).collect(),
+            _ => Default::default(),
+        };
+        let fields = keys
+            .into_iter()
+            .map(|(key, source_field)| {
+                let value_type = self
+                    .value_type
+                    .substitute_type_param(
+                        &self.key_name,
+                        &TypeInfo::LitString(LitString { s: key.clone() }),
+                    )
+                    .resolve_names(types_by_name_by_file, type_params);
+                let value_type = match (self.optional, value_type) {
+                    (Some(MappedModifier::Remove), TypeInfo::Optional { item_type }) => *item_type,
+                    (Some(MappedModifier::Add), t @ TypeInfo::Optional { .. }) => t,
+                    (Some(MappedModifier::Add), t) => TypeInfo::Optional {
+                        item_type: Box::new(t),
+                    },
+                    // homomorphic mapped types (over keyof T) preserve the
+                    // optionality of T's properties
+                    (None, t)
+                        if !matches!(t, TypeInfo::Optional { .. })
+                            && matches!(source_field, Some(TypeInfo::Optional { .. })) =>
+                    {
+                        TypeInfo::Optional {
+                            item_type: Box::new(t),
+                        }
+                    }
+                    (_, t) => t,
+                };
+                (key, value_type)
+            })
+            .collect();
+
+        TypeInfo::Interface(Interface {
+            indexer: None,
+            extends: Default::default(),
+            fields,
+            readonly_fields,
+            type_params: Default::default(),
+            constructor: None,
+            callable: None,
+            iterator: None,
+            async_iterator: None,
+        })
+    }
+}
+
+/// Determine the full set of keys for a mapped type, along with the type of the
+/// property each key came from for `keyof T` key sets.
+/// Returns `None` if the set of keys is open (e.g. `string` or `keyof` a type parameter).
+fn mapped_keys(
+    types_by_name_by_file: &HashMap<PathBuf, HashMap<TypeIdent, Type>>,
+    type_params: &HashMap<String, TypeParamConfig>,
+    key_type: &TypeInfo,
+) -> Option<Vec<(String, Option<TypeInfo>)>> {
+    match key_type {
+        TypeInfo::KeyOf { target } => {
+            let target = target.resolve_names(types_by_name_by_file, type_params);
+            if is_type_param_ref(&target, type_params) {
+                return None;
+            }
+
+            let target = deref_type(types_by_name_by_file, &target)?;
+            structural_fields(types_by_name_by_file, &target)
+                .map(|fields| fields.into_iter().map(|(n, t)| (n, Some(t))).collect())
+        }
+        _ => {
+            let key_type = key_type.resolve_names(types_by_name_by_file, type_params);
+            if is_type_param_ref(&key_type, type_params) {
+                return None;
+            }
+
+            literal_keys(&deref_type(types_by_name_by_file, &key_type)?)
+                .map(|keys| keys.into_iter().map(|k| (k, None)).collect())
+        }
+    }
+}
+
+/// Return the names of all properties and methods of an interface or class.
+fn property_names(
+    types_by_name_by_file: &HashMap<PathBuf, HashMap<TypeIdent, Type>>,
+    typ: &TypeInfo,
+) -> Option<Vec<String>> {
+    let fields = structural_fields(types_by_name_by_file, typ)?;
+    let methods: Vec<_> = match typ {
+        TypeInfo::Class(Class { members, .. }) => members
+            .iter()
+            .filter(|(_, m)| matches!(m, Member::Method(_)))
+            .map(|(n, _)| n.js_name().to_string())
+            .collect(),
+        _ => Default::default(),
+    };
+
+    let mut names: Vec<_> = fields
+        .into_keys()
+        .chain(methods.into_iter())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    names.sort();
+    Some(names)
+}
+
+/// Produce an enum of the property names of `target` to represent `keyof target`.
+/// Returns `None` if we don't know the properties of `target`.
+fn key_enum(
+    types_by_name_by_file: &HashMap<PathBuf, HashMap<TypeIdent, Type>>,
+    type_params: &HashMap<String, TypeParamConfig>,
+    target: &TypeInfo,
+) -> Option<TypeInfo> {
+    let target = target.resolve_names(types_by_name_by_file, type_params);
+    if is_type_param_ref(&target, type_params) {
+        return None;
+    }
+
+    let target = deref_type(types_by_name_by_file, &target)?;
+    let names = property_names(types_by_name_by_file, &target)?;
+    if names.is_empty() {
+        return None;
+    }
+
+    Some(TypeInfo::Enum(Enum {
+        members: names
+            .into_iter()
+            .map(|n| EnumMember {
+                id: n.clone(),
+                value: Some(EnumValue::Str(n)),
+            })
+            .collect(),
+    }))
+}
+
+/// Return the property names represented by a literal type or a union of literal types.
+fn literal_keys(typ: &TypeInfo) -> Option<Vec<String>> {
+    match typ {
+        TypeInfo::LitString(LitString { s }) => Some(vec![s.clone()]),
+        TypeInfo::LitNumber(LitNumber { n }) => Some(vec![n.to_string()]),
+        TypeInfo::Union(Union { types }) => types
+            .iter()
+            .map(literal_keys)
+            .collect::<Option<Vec<_>>>()
+            .map(|keys| keys.into_iter().flatten().collect()),
+        _ => None,
+    }
+}
+
+/// An indexed access type, `object_type[index_type]`, resolved to the
+/// type of the indexed property or element during name resolution (or `any`
+/// if it cannot be resolved).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexedAccess {
+    pub object_type: Box<TypeInfo>,
+    pub index_type: Box<TypeInfo>,
+}
+
+impl IndexedAccess {
+    fn resolve_names(
+        &self,
+        types_by_name_by_file: &HashMap<PathBuf, HashMap<TypeIdent, Type>>,
+        type_params: &HashMap<String, TypeParamConfig>,
+    ) -> TypeInfo {
+        let object_type = self
+            .object_type
+            .resolve_names(types_by_name_by_file, type_params);
+        let index_type = self
+            .index_type
+            .resolve_names(types_by_name_by_file, type_params);
+
+        if is_type_param_ref(&object_type, type_params)
+            || is_type_param_ref(&index_type, type_params)
+        {
+            return TypeInfo::PrimitiveAny(PrimitiveAny());
+        }
+
+        deref_type(types_by_name_by_file, &object_type)
+            .and_then(|object_type| {
+                index_into_type(types_by_name_by_file, &object_type, &index_type)
+            })
+            .unwrap_or(TypeInfo::PrimitiveAny(PrimitiveAny()))
+    }
+}
+
+/// Return the type of `object_type[index_type]`.
+fn index_into_type(
+    types_by_name_by_file: &HashMap<PathBuf, HashMap<TypeIdent, Type>>,
+    object_type: &TypeInfo,
+    index_type: &TypeInfo,
+) -> Option<TypeInfo> {
+    let index_type = deref_type(types_by_name_by_file, index_type)?;
+    match (object_type, &index_type) {
+        (_, TypeInfo::Union(Union { types })) => types
+            .iter()
+            .map(|t| index_into_type(types_by_name_by_file, object_type, t))
+            .collect::<Option<Vec<_>>>()
+            .map(|types| TypeInfo::Union(Union { types })),
+        // `keyof T` is an enum of the property names of T
+        (_, TypeInfo::Enum(Enum { members })) => members
+            .iter()
+            .map(|m| match &m.value {
+                Some(EnumValue::Str(s)) => Some(TypeInfo::LitString(LitString { s: s.clone() })),
+                Some(EnumValue::Num(n)) => Some(TypeInfo::LitNumber(LitNumber { n: *n })),
+                _ => None,
+            })
+            .map(|key| index_into_type(types_by_name_by_file, object_type, &key?))
+            .collect::<Option<Vec<_>>>()
+            .map(union_of_distinct),
+        (TypeInfo::Interface(iface), TypeInfo::LitString(LitString { s })) => {
+            structural_fields(types_by_name_by_file, object_type)
+                .and_then(|mut fields| fields.remove(s))
+                .or_else(|| iface.indexer.as_ref().map(|i| (*i.type_info).clone()))
+                .map(|t| t.resolve_names(types_by_name_by_file, &Default::default()))
+        }
+        (TypeInfo::Class(class), TypeInfo::LitString(LitString { s })) => {
+            structural_fields(types_by_name_by_file, object_type)
+                .and_then(|mut fields| fields.remove(s))
+                .or_else(
+                    || match class.members.get(&MemberKey::Instance(s.clone())) {
+                        Some(Member::Method(fg)) => Some(TypeInfo::FuncGroup(fg.clone())),
+                        _ => None,
+                    },
+                )
+                .map(|t| t.resolve_names(types_by_name_by_file, &Default::default()))
+        }
+        (
+            TypeInfo::Interface(Interface {
+                indexer: Some(indexer),
+                ..
+            }),
+            TypeInfo::PrimitiveString(_) | TypeInfo::PrimitiveNumber(_),
+        ) => Some((*indexer.type_info).clone()),
+        (TypeInfo::Mapped { value_type }, _) => Some((**value_type).clone()),
+        (
+            TypeInfo::Array { item_type, .. },
+            TypeInfo::PrimitiveNumber(_) | TypeInfo::LitNumber(_),
+        ) => Some((**item_type).clone()),
+        (
+            TypeInfo::Tuple(Tuple {
+                types, has_rest, ..
+            }),
+            TypeInfo::LitNumber(LitNumber { n }),
+        ) => {
+            if n.fract() == 0.0 && *n >= 0.0 {
+                let idx = *n as usize;
+                if *has_rest && idx + 1 >= types.len() {
+                    types.last().map(rest_item_type)
+                } else {
+                    types.get(idx).cloned()
+                }
+            } else {
+                None
+            }
+        }
+        (
+            TypeInfo::Tuple(Tuple {
+                types, has_rest, ..
+            }),
+            TypeInfo::PrimitiveNumber(_),
+        ) => {
+            let mut types = types.clone();
+            if *has_rest {
+                if let Some(rest) = types.pop() {
+                    types.push(rest_item_type(&rest));
+                }
+            }
+            Some(TypeInfo::Union(Union { types }))
+        }
+        _ => None,
+    }
+}
+
+fn rest_item_type(rest: &TypeInfo) -> TypeInfo {
+    match rest {
+        TypeInfo::Array { item_type, .. } => (**item_type).clone(),
+        t => t.clone(),
+    }
+}
+
 macro_rules! make_primitives {
     () => {};
     ($prim:ident) => {
@@ -399,6 +982,11 @@ make_primitives!(
     PrimitiveNull,
 );
 
+/// The polymorphic `this` type, referring to the type of the enclosing class
+/// or interface (or a subtype thereof).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolymorphicThis();
+
 with_web_sys_types!(
     #[derive(Debug, Clone, PartialEq, Eq)]
     pub struct WebSysBuiltin(pub String);
@@ -479,6 +1067,127 @@ pub struct BuiltinPromise {
     pub value_type: Box<TypeInfo>,
 }
 
+/// The return type of a type guard, `param_name is asserted_type`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypePredicate {
+    pub param_name: String,
+    pub asserted_type: Box<TypeInfo>,
+}
+
+/// A template literal type, `` `a${B}c` ``, made up of the literal `quasis`
+/// surrounding each of the interpolated `types`. There is always one more
+/// quasi than there are types.
+/// Template literals whose types are all finite sets of literals are expanded
+/// into a union of string literals during name resolution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateLiteral {
+    pub quasis: Vec<String>,
+    pub types: Vec<TypeInfo>,
+}
+
+/// The most string literals we will expand a template literal type into
+/// before treating it as an open-ended pattern instead
+const MAX_TEMPLATE_LITERAL_EXPANSION: usize = 256;
+
+impl TemplateLiteral {
+    fn resolve_names(
+        &self,
+        types_by_name_by_file: &HashMap<PathBuf, HashMap<TypeIdent, Type>>,
+        type_params: &HashMap<String, TypeParamConfig>,
+    ) -> TypeInfo {
+        let types: Vec<_> = self
+            .types
+            .iter()
+            .map(|t| t.resolve_names(types_by_name_by_file, type_params))
+            .collect();
+        let holes: Vec<_> = types
+            .iter()
+            .map(|t| template_hole_literals(types_by_name_by_file, t))
+            .collect();
+
+        if let Some(strings) = self.expand(&holes) {
+            return union_of(
+                strings
+                    .into_iter()
+                    .map(|s| TypeInfo::LitString(LitString { s }))
+                    .collect(),
+            );
+        }
+
+        // we can't expand all of our holes but the finite ones can at
+        // least be narrowed down to their string literals
+        TypeInfo::TemplateLiteral(TemplateLiteral {
+            quasis: self.quasis.clone(),
+            types: types
+                .into_iter()
+                .zip(holes.into_iter())
+                .map(|(t, hole)| match hole {
+                    Some(literals) => union_of(
+                        literals
+                            .into_iter()
+                            .map(|s| TypeInfo::LitString(LitString { s }))
+                            .collect(),
+                    ),
+                    None => t,
+                })
+                .collect(),
+        })
+    }
+
+    /// Expand into every string we may represent if all of our `holes` are
+    /// finite and there are a reasonable number of combinations of them.
+    fn expand(&self, holes: &[Option<Vec<String>>]) -> Option<Vec<String>> {
+        let holes = holes.iter().cloned().collect::<Option<Vec<_>>>()?;
+        let count = holes
+            .iter()
+            .try_fold(1usize, |count, hole| count.checked_mul(hole.len()))?;
+        if count > MAX_TEMPLATE_LITERAL_EXPANSION {
+            return None;
+        }
+
+        let mut quasis = self.quasis.iter();
+        let mut strings = vec![quasis.next().cloned().unwrap_or_default()];
+        for (hole, quasi) in holes.iter().zip(quasis) {
+            strings = strings
+                .iter()
+                .flat_map(|prefix| {
+                    hole.iter()
+                        .map(move |h| format!("{}{}{}", prefix, h, quasi))
+                })
+                .collect();
+        }
+        Some(strings)
+    }
+}
+
+/// The strings that a template literal hole of type `typ` may hold, if that
+/// is a finite set.
+fn template_hole_literals(
+    types_by_name_by_file: &HashMap<PathBuf, HashMap<TypeIdent, Type>>,
+    typ: &TypeInfo,
+) -> Option<Vec<String>> {
+    union_members(types_by_name_by_file, typ.clone())
+        .into_iter()
+        .map(|t| match t {
+            TypeInfo::LitString(LitString { s }) => Some(vec![s]),
+            TypeInfo::LitNumber(LitNumber { n }) => Some(vec![n.to_string()]),
+            TypeInfo::LitBoolean(LitBoolean { b }) => Some(vec![b.to_string()]),
+            TypeInfo::LitBigInt(LitBigInt { n }) => Some(vec![n]),
+            TypeInfo::PrimitiveBoolean(_) => Some(vec!["true".to_string(), "false".to_string()]),
+            TypeInfo::PrimitiveNull(_) => Some(vec!["null".to_string()]),
+            TypeInfo::PrimitiveUndefined(_) => Some(vec!["undefined".to_string()]),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()
+        .map(|literals| literals.into_iter().flatten().collect())
+}
+
+/// `AsyncIterable<T>` and the async iterators and generators producing `T`s
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuiltinAsyncIterable {
+    pub item_type: Box<TypeInfo>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct LitNumber {
     pub n: f64,
@@ -496,6 +1205,12 @@ pub struct LitBoolean {
     pub b: bool,
 }
 
+/// A bigint literal, e.g. `10n`, holding the decimal digits of its value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LitBigInt {
+    pub n: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, StrumDisplay)]
 pub enum TypeInfo {
     Interface(Interface),
@@ -511,24 +1226,44 @@ pub enum TypeInfo {
     PrimitiveVoid(PrimitiveVoid),
     PrimitiveUndefined(PrimitiveUndefined),
     PrimitiveNull(PrimitiveNull),
+    PolymorphicThis(PolymorphicThis),
     BuiltinPromise(BuiltinPromise),
+    BuiltinAsyncIterable(BuiltinAsyncIterable),
     WebSysBuiltin(WebSysBuiltin),
     JsSysBuiltin(JsSysBuiltin),
-    Array { item_type: Box<TypeInfo> },
+    Array {
+        item_type: Box<TypeInfo>,
+        readonly: bool,
+    },
     Tuple(Tuple),
-    Optional { item_type: Box<TypeInfo> },
+    Optional {
+        item_type: Box<TypeInfo>,
+    },
     Union(Union),
     Intersection(Intersection),
-    Mapped { value_type: Box<TypeInfo> },
+    Mapped {
+        value_type: Box<TypeInfo>,
+    },
     LitNumber(LitNumber),
     LitString(LitString),
     LitBoolean(LitBoolean),
+    LitBigInt(LitBigInt),
     FuncGroup(FuncGroup),
     Constructor(Ctor),
     Class(Class),
-    Var { type_info: Box<TypeInfo> },
+    Var {
+        type_info: Box<TypeInfo>,
+    },
     NamespaceImport(NamespaceImport),
     TypeQuery(TypeQuery),
+    Conditional(Conditional),
+    MappedType(MappedType),
+    KeyOf {
+        target: Box<TypeInfo>,
+    },
+    IndexedAccess(IndexedAccess),
+    TypePredicate(TypePredicate),
+    TemplateLiteral(TemplateLiteral),
 }
 
 fn resolve_builtin(
@@ -547,11 +1282,12 @@ fn resolve_builtin(
         TypeIdent::TypeEnvironmentParent() => return None,
     };
 
-    if name == "Array" {
+    if name == "Array" || name == "ReadonlyArray" {
         assert_eq!(
             alias_type_params.len(),
             1,
-            "expected 1 type param for Array"
+            "expected 1 type param for {}",
+            name
         );
         return Some(TypeInfo::Array {
             item_type: Box::new(
@@ -561,6 +1297,7 @@ fn resolve_builtin(
                     .unwrap()
                     .resolve_names(types_by_name_by_file, type_params),
             ),
+            readonly: name == "ReadonlyArray",
         });
     }
 
@@ -574,6 +1311,7 @@ fn resolve_builtin(
                     name: "args".to_string(),
                     type_info: TypeInfo::Array {
                         item_type: Box::new(TypeInfo::PrimitiveAny(PrimitiveAny())),
+                        readonly: false,
                     },
                     is_variadic: true,
                 }],
@@ -599,6 +1337,22 @@ fn resolve_builtin(
         }));
     }
 
+    if name == "AsyncIterable"
+        || name == "AsyncIterableIterator"
+        || name == "AsyncIterator"
+        || name == "AsyncGenerator"
+    {
+        return Some(TypeInfo::BuiltinAsyncIterable(BuiltinAsyncIterable {
+            item_type: Box::new(
+                alias_type_params
+                    .first()
+                    .as_ref()
+                    .map(|p| p.resolve_names(types_by_name_by_file, type_params))
+                    .unwrap_or(TypeInfo::PrimitiveAny(PrimitiveAny())),
+            ),
+        }));
+    }
+
     if let Ok(web_sys_builtin) = WebSysBuiltin::try_from(name) {
         return Some(TypeInfo::WebSysBuiltin(web_sys_builtin));
     }
@@ -617,13 +1371,13 @@ fn recursive_class_fields<F, B>(
     fields: F,
 ) -> HashMap<String, TypeInfo>
 where
-    F: IntoIterator<Item = (String, Member)>,
+    F: IntoIterator<Item = (MemberKey, Member)>,
     B: IntoIterator<Item = TypeRef>,
 {
     fields
         .into_iter()
         .filter_map(|(n, m)| match m {
-            Member::Property(ti) => Some((n, ti)),
+            Member::Property(p) => Some((n.js_name().to_string(), p.type_info)),
             _ => None,
         })
         .chain(
@@ -729,28 +1483,57 @@ where
     F: Fn(&str, TypeInfo) -> Option<TypeInfo>,
 {
     match typ {
-        TypeInfo::Interface(iface) => TypeInfo::Interface(Interface {
-            indexer: iface.indexer.and_then(|indexer| {
-                mapper("", *indexer.type_info).map(|type_info| Indexer {
-                    readonly: indexer.readonly,
-                    type_info: Box::new(type_info),
-                })
-            }),
-            extends: Default::default(),
-            type_params: iface.type_params,
-            constructor: iface.constructor,
-            fields: recursive_iface_fields(types_by_name_by_file, iface.extends, iface.fields)
-                .into_iter()
-                .filter_map(|(n, f)| mapper(&n, f).map(|f| (n, f)))
-                .collect(),
-        }),
+        TypeInfo::Interface(iface) => {
+            let fields: HashMap<_, _> =
+                recursive_iface_fields(types_by_name_by_file, iface.extends, iface.fields)
+                    .into_iter()
+                    .filter_map(|(n, f)| mapper(&n, f).map(|f| (n, f)))
+                    .collect();
+            TypeInfo::Interface(Interface {
+                indexer: iface.indexer.and_then(|indexer| {
+                    mapper("", *indexer.type_info).map(|type_info| Indexer {
+                        readonly: indexer.readonly,
+                        type_info: Box::new(type_info),
+                    })
+                }),
+                extends: Default::default(),
+                type_params: iface.type_params,
+                constructor: iface.constructor,
+                // like typescript, we drop call signatures when mapping fields.
+                // we also drop iterators so that mapped types remain plain data
+                callable: None,
+                iterator: None,
+                async_iterator: None,
+                readonly_fields: iface
+                    .readonly_fields
+                    .into_iter()
+                    .filter(|n| fields.contains_key(n))
+                    .collect(),
+                fields,
+            })
+        }
         TypeInfo::Class(class) => {
-            type NamedMembers = Vec<(String, Member)>;
+            type NamedMembers = Vec<(MemberKey, Member)>;
             let (ctors, fields): (NamedMembers, NamedMembers) = class
                 .members
                 .into_iter()
                 .partition(|(_, m)| matches!(m, Member::Constructor(_)));
 
+            let readonly_props: HashSet<_> = fields
+                .iter()
+                .filter(|(_, m)| matches!(m, Member::Property(Property { readonly: true, .. })))
+                .map(|(n, _)| n.js_name().to_string())
+                .collect();
+            let fields: HashMap<_, _> = recursive_class_fields(
+                types_by_name_by_file,
+                class.super_class,
+                class.implements,
+                fields,
+            )
+            .into_iter()
+            .filter_map(|(n, f)| mapper(&n, f).map(|f| (n, f)))
+            .collect();
+
             TypeInfo::Interface(Interface {
                 indexer: None,
                 extends: Default::default(),
@@ -759,17 +1542,102 @@ where
                     Member::Constructor(ctor) => ctor.overloads.into_iter().last(),
                     _ => None,
                 }),
-                fields: recursive_class_fields(
-                    types_by_name_by_file,
-                    class.super_class,
-                    class.implements,
-                    fields,
-                )
-                .into_iter()
-                .filter_map(|(n, f)| mapper(&n, f).map(|f| (n, f)))
-                .collect(),
+                callable: None,
+                iterator: None,
+                async_iterator: None,
+                readonly_fields: readonly_props
+                    .into_iter()
+                    .filter(|n| fields.contains_key(n))
+                    .collect(),
+                fields,
+            })
+        }
+        _ => typ,
+    }
+}
+
+/// Flatten `typ`, which may refer to a union, into its members.
+/// Non-union types are their own only member.
+fn union_members(
+    types_by_name_by_file: &HashMap<PathBuf, HashMap<TypeIdent, Type>>,
+    typ: TypeInfo,
+) -> Vec<TypeInfo> {
+    // `keyof T` resolves to an anonymous enum of its keys
+    if let TypeInfo::Enum(Enum { members }) = &typ {
+        let keys: Option<Vec<_>> = members
+            .iter()
+            .map(|m| match &m.value {
+                Some(EnumValue::Str(s)) => Some(TypeInfo::LitString(LitString { s: s.clone() })),
+                _ => None,
             })
+            .collect();
+        if let Some(keys) = keys {
+            return keys;
+        }
+    }
+
+    match deref_type(types_by_name_by_file, &typ) {
+        Some(TypeInfo::Union(Union { types })) => types
+            .into_iter()
+            .flat_map(|t| union_members(types_by_name_by_file, t))
+            .collect(),
+        _ => vec![typ],
+    }
+}
+
+/// Build a union from `types`, collapsing unions of one type to that type.
+fn union_of(mut types: Vec<TypeInfo>) -> TypeInfo {
+    match types.len() {
+        // we represent never as undefined
+        0 => TypeInfo::PrimitiveUndefined(PrimitiveUndefined()),
+        1 => types.remove(0),
+        _ => TypeInfo::Union(Union { types }),
+    }
+}
+
+/// Build a union from `types` like `union_of`, dropping duplicate types.
+fn union_of_distinct<T: IntoIterator<Item = TypeInfo>>(types: T) -> TypeInfo {
+    union_of(types.into_iter().fold(Vec::new(), |mut distinct, t| {
+        if !distinct.contains(&t) {
+            distinct.push(t);
+        }
+        distinct
+    }))
+}
+
+/// Apply the intrinsic string manipulation type, `name`, to `s`.
+fn manipulate_string(name: &str, s: &str) -> String {
+    let mut chars = s.chars();
+    match name {
+        "Uppercase" => s.to_uppercase(),
+        "Lowercase" => s.to_lowercase(),
+        "Capitalize" => chars
+            .next()
+            .map(|c| c.to_uppercase().chain(chars).collect())
+            .unwrap_or_default(),
+        "Uncapitalize" => chars
+            .next()
+            .map(|c| c.to_lowercase().chain(chars).collect())
+            .unwrap_or_default(),
+        _ => panic!("unknown string manipulation type, {}", name),
+    }
+}
+
+/// Unwrap promises in `typ` the way `await` does, recursively.
+fn awaited(
+    types_by_name_by_file: &HashMap<PathBuf, HashMap<TypeIdent, Type>>,
+    typ: TypeInfo,
+) -> TypeInfo {
+    match deref_type(types_by_name_by_file, &typ) {
+        Some(TypeInfo::BuiltinPromise(BuiltinPromise { value_type })) => {
+            awaited(types_by_name_by_file, *value_type)
         }
+        Some(TypeInfo::Union(Union { types })) => union_of(
+            types
+                .into_iter()
+                .map(|t| awaited(types_by_name_by_file, t))
+                .collect(),
+        ),
         _ => typ,
     }
 }
@@ -810,33 +1678,104 @@ fn resolve_utility(
         });
     }
 
-    if name == "Readonly" {
+    if name == "Exclude" || name == "Extract" {
+        assert_eq!(
+            alias_type_params.len(),
+            2,
+            "expected 2 type params for {}",
+            name
+        );
+
+        let filter_type = alias_type_params
+            .get(1)
+            .unwrap()
+            .resolve_names(types_by_name_by_file, type_params);
+        // Extract keeps the members assignable to the filter, Exclude drops them
+        let keep_assignable = name == "Extract";
+        let members = union_members(
+            types_by_name_by_file,
+            alias_type_params
+                .get(0)
+                .unwrap()
+                .resolve_names(types_by_name_by_file, type_params),
+        )
+        .into_iter()
+        .filter(|t| {
+            // keep anything we can't decide on
+            is_assignable(types_by_name_by_file, type_params, t, &filter_type, 0)
+                .map(|is_assignable| is_assignable == keep_assignable)
+                .unwrap_or(true)
+        })
+        .collect();
+
+        return Some(union_of(members));
+    }
+
+    if name == "ThisType" {
         assert_eq!(
             alias_type_params.len(),
             1,
-            "expected 1 type param for Readonly"
+            "expected 1 type param for ThisType"
         );
 
-        // TODO: handle readonly properly
-        return alias_type_params.get(0).map(Clone::clone);
+        // ThisType is an empty marker interface that only affects the type of
+        // `this` in object literals so it contributes nothing to our types
+        return Some(TypeInfo::Interface(Interface {
+            indexer: None,
+            extends: Default::default(),
+            fields: Default::default(),
+            readonly_fields: Default::default(),
+            type_params: Default::default(),
+            constructor: None,
+            callable: None,
+            iterator: None,
+            async_iterator: None,
+        }));
     }
 
-    if name == "Exclude"
-        || name == "Extract"
-        || name == "ThisType"
-        || name == "Uppercase"
-        || name == "Lowercase"
-        || name == "Capitalize"
-        || name == "Uncapitalize"
+    if name == "Uppercase" || name == "Lowercase" || name == "Capitalize" || name == "Uncapitalize"
     {
         assert_eq!(
             alias_type_params.len(),
-            2,
-            "expected 2 type params for Exclude, Extract, ThisType, Uppercase, Lowercase, Capitalize, Uncapitalize"
+            1,
+            "expected 1 type param for {}",
+            name
+        );
+
+        let members = union_members(
+            types_by_name_by_file,
+            alias_type_params
+                .get(0)
+                .unwrap()
+                .resolve_names(types_by_name_by_file, type_params),
+        )
+        .into_iter()
+        .map(|t| match t {
+            TypeInfo::LitString(LitString { s }) => TypeInfo::LitString(LitString {
+                s: manipulate_string(name, &s),
+            }),
+            // anything but a literal (e.g. string or a type param) can't be
+            // evaluated so we keep it as is
+            t => t,
+        })
+        .collect();
+
+        return Some(union_of(members));
+    }
+
+    if name == "Awaited" {
+        assert_eq!(
+            alias_type_params.len(),
+            1,
+            "expected 1 type param for Awaited"
         );
 
-        // TODO: handle these properly
-        return alias_type_params.get(0).map(Clone::clone);
+        return alias_type_params.get(0).map(|p| {
+            awaited(
+                types_by_name_by_file,
+                p.resolve_names(types_by_name_by_file, type_params),
+            )
+        });
     }
 
     let resolve_type = |ti: Option<&TypeInfo>| {
@@ -859,6 +1798,55 @@ fn resolve_utility(
             })
     };
 
+    if name == "Readonly" {
+        assert_eq!(
+            alias_type_params.len(),
+            1,
+            "expected 1 type param for Readonly"
+        );
+
+        return alias_type_params
+            .get(0)
+            .map(|p| p.resolve_names(types_by_name_by_file, type_params))
+            .map(|t| {
+                // named interfaces become readonly copies of themselves
+                let t = match &t {
+                    TypeInfo::Ref(tr) => match lookup_type(types_by_name_by_file, &tr.referent) {
+                        Some(Type {
+                            info: TypeInfo::Interface(iface),
+                            ..
+                        }) => TypeInfo::Interface(iface.clone()),
+                        _ => t,
+                    },
+                    _ => t,
+                };
+                match t {
+                    TypeInfo::Array { item_type, .. } => TypeInfo::Array {
+                        item_type,
+                        readonly: true,
+                    },
+                    TypeInfo::Interface(iface) => {
+                        match type_with_filter_mapped_fields(
+                            types_by_name_by_file,
+                            TypeInfo::Interface(iface),
+                            |_, ti| Some(ti),
+                        ) {
+                            TypeInfo::Interface(mut iface) => {
+                                iface.readonly_fields = iface.fields.keys().cloned().collect();
+                                if let Some(indexer) = &mut iface.indexer {
+                                    indexer.readonly = true;
+                                }
+                                TypeInfo::Interface(iface)
+                            }
+                            t => t,
+                        }
+                    }
+                    // references to classes keep their identity and methods
+                    t => t,
+                }
+            });
+    }
+
     if name == "Partial" {
         assert_eq!(
             alias_type_params.len(),
@@ -960,6 +1948,33 @@ fn resolve_utility(
         });
     }
 
+    if name == "Omit" {
+        assert_eq!(
+            alias_type_params.len(),
+            2,
+            "expected 2 type params for Omit"
+        );
+
+        let keys = alias_type_params
+            .get(1)
+            .expect("need a keys type param for Omit");
+        // if we don't know which keys to omit (e.g. `Omit<T, string>`), we
+        // keep every field
+        let omitted: HashSet<String> = mapped_keys(types_by_name_by_file, type_params, keys)
+            .map(|keys| keys.into_iter().map(|(k, _)| k).collect())
+            .unwrap_or_default();
+
+        return resolve_type(alias_type_params.get(0)).map(|p| {
+            type_with_filter_mapped_fields(types_by_name_by_file, p, |n, f| {
+                if omitted.contains(n) {
+                    None
+                } else {
+                    Some(f)
+                }
+            })
+        });
+    }
+
     if name == "Parameters" {
         assert_eq!(
             alias_type_params.len(),
@@ -968,17 +1983,23 @@ fn resolve_utility(
         );
 
         return resolve_type(alias_type_params.get(0)).map(|p| match p {
-            TypeInfo::FuncGroup(f) => TypeInfo::Tuple(Tuple {
-                types: f
+            TypeInfo::FuncGroup(f) => {
+                let params = f
                     .overloads
                     .into_iter()
                     .last()
-                    .map(|f| f.params.into_iter().map(|param| param.type_info).collect())
-                    .unwrap_or_default(),
-            }),
+                    .map(|f| f.params)
+                    .unwrap_or_default();
+                TypeInfo::Tuple(Tuple {
+                    has_rest: params.last().map(|p| p.is_variadic).unwrap_or(false),
+                    labels: params.iter().map(|p| p.name.clone()).collect(),
+                    types: params.into_iter().map(|p| p.type_info).collect(),
+                })
+            }
             TypeInfo::Ref(tr) if tr.referent.name == TypeIdent::Name("Function".to_string()) => {
                 TypeInfo::Tuple(Tuple {
                     types: tr.type_params[0..tr.type_params.len() - 1].to_vec(),
+                    ..Default::default()
                 })
             }
             _ => {
@@ -1001,6 +2022,7 @@ fn resolve_utility(
                     .into_iter()
                     .flat_map(|c| c.params.into_iter().map(|p| p.type_info))
                     .collect(),
+                ..Default::default()
             }),
             TypeInfo::Class(Class { members, .. }) => TypeInfo::Tuple(Tuple {
                 types: members
@@ -1015,6 +2037,7 @@ fn resolve_utility(
                     .into_iter()
                     .flat_map(|params| params.into_iter().map(|p| p.type_info))
                     .collect(),
+                ..Default::default()
             }),
             _ => {
                 // TODO: error
@@ -1131,8 +2154,12 @@ impl TypeInfo {
                 indexer,
                 extends,
                 fields,
+                readonly_fields,
                 type_params: iface_type_params,
                 constructor,
+                callable,
+                iterator,
+                async_iterator,
             }) => {
                 let iface_type_params =
                     resolve_type_params(types_by_name_by_file, type_params, iface_type_params);
@@ -1161,15 +2188,28 @@ impl TypeInfo {
                             )
                         })
                         .collect(),
+                    readonly_fields: readonly_fields.clone(),
                     type_params: iface_type_params,
                     constructor: constructor
                         .as_ref()
                         .map(|c| c.resolve_names(types_by_name_by_file, &our_type_params)),
+                    callable: callable
+                        .as_ref()
+                        .map(|c| c.resolve_names(types_by_name_by_file, &our_type_params)),
+                    iterator: iterator.as_ref().map(|t| {
+                        Box::new(t.resolve_names(types_by_name_by_file, &our_type_params))
+                    }),
+                    async_iterator: async_iterator.as_ref().map(|t| {
+                        Box::new(t.resolve_names(types_by_name_by_file, &our_type_params))
+                    }),
                 })
             }
             Self::Ref(tr) => tr
                 .resolve_names(types_by_name_by_file, type_params)
-                .map(Self::Ref)
+                .map(|tr| {
+                    instantiate_conditional_alias(types_by_name_by_file, type_params, &tr)
+                        .unwrap_or(Self::Ref(tr))
+                })
                 .or_else(|| {
                     resolve_builtin(
                         &tr.referent,
@@ -1190,9 +2230,19 @@ impl TypeInfo {
                     println!("unresolved alias {:?}", &tr.referent);
                     Self::PrimitiveAny(PrimitiveAny())
                 }),
-            Self::Alias(a) => Self::Alias(a.resolve_names(types_by_name_by_file, type_params)),
-            Self::Array { item_type } => Self::Array {
+            Self::Alias(a) => namespace_alias_target(types_by_name_by_file, a)
+                .map(|(src, name_parts)| {
+                    Self::NamespaceImport(NamespaceImport::Namespace { src, name_parts })
+                })
+                .unwrap_or_else(|| {
+                    Self::Alias(a.resolve_names(types_by_name_by_file, type_params))
+                }),
+            Self::Array {
+                item_type,
+                readonly,
+            } => Self::Array {
                 item_type: Box::new(item_type.resolve_names(types_by_name_by_file, type_params)),
+                readonly: *readonly,
             },
             Self::Optional { item_type } => Self::Optional {
                 item_type: Box::new(item_type.resolve_names(types_by_name_by_file, type_params)),
@@ -1209,11 +2259,17 @@ impl TypeInfo {
                     .map(|t| t.resolve_names(types_by_name_by_file, type_params))
                     .collect(),
             }),
-            Self::Tuple(Tuple { types }) => Self::Tuple(Tuple {
+            Self::Tuple(Tuple {
+                types,
+                labels,
+                has_rest,
+            }) => Self::Tuple(Tuple {
                 types: types
                     .iter()
                     .map(|t| t.resolve_names(types_by_name_by_file, type_params))
                     .collect(),
+                labels: labels.clone(),
+                has_rest: *has_rest,
             }),
             Self::Mapped { value_type } => Self::Mapped {
                 value_type: Box::new(value_type.resolve_names(types_by_name_by_file, type_params)),
@@ -1250,7 +2306,7 @@ impl TypeInfo {
                         .map(Box::new),
                     members: members
                         .iter()
-                        .map(|(n, m)| (n.to_string(), m.resolve_names(types_by_name_by_file, &tps)))
+                        .map(|(n, m)| (n.clone(), m.resolve_names(types_by_name_by_file, &tps)))
                         .collect(),
                     type_params: class_type_params,
                     implements: implements
@@ -1275,14 +2331,445 @@ impl TypeInfo {
             Self::PrimitiveVoid(PrimitiveVoid()) => self.clone(),
             Self::PrimitiveUndefined(PrimitiveUndefined()) => self.clone(),
             Self::PrimitiveNull(PrimitiveNull()) => self.clone(),
+            Self::PolymorphicThis(PolymorphicThis()) => self.clone(),
             Self::LitNumber(_) => self.clone(),
             Self::LitString(_) => self.clone(),
             Self::LitBoolean(_) => self.clone(),
+            Self::LitBigInt(_) => self.clone(),
             Self::BuiltinPromise(_) => self.clone(),
+            Self::BuiltinAsyncIterable(_) => self.clone(),
             Self::WebSysBuiltin(_) => self.clone(),
             Self::JsSysBuiltin(_) => self.clone(),
             Self::NamespaceImport { .. } => self.clone(),
             Self::TypeQuery(TypeQuery::LookupRef(_)) => self.clone(),
+            Self::Conditional(c) => c.resolve_names(types_by_name_by_file, type_params),
+            Self::MappedType(m) => m.resolve_names(types_by_name_by_file, type_params),
+            // keyof outside of a mapped type
+            Self::KeyOf { target } => key_enum(types_by_name_by_file, type_params, target)
+                .unwrap_or(Self::PrimitiveString(PrimitiveString())),
+            Self::IndexedAccess(i) => i.resolve_names(types_by_name_by_file, type_params),
+            Self::TemplateLiteral(t) => t.resolve_names(types_by_name_by_file, type_params),
+            Self::TypePredicate(TypePredicate {
+                param_name,
+                asserted_type,
+            }) => Self::TypePredicate(TypePredicate {
+                param_name: param_name.clone(),
+                asserted_type: Box::new(
+                    asserted_type.resolve_names(types_by_name_by_file, type_params),
+                ),
+            }),
+        }
+    }
+
+    /// Replace references to the type parameter, `name`, with `replacement`.
+    fn substitute_type_param(&self, name: &str, replacement: &TypeInfo) -> Self {
+        let sub = |t: &TypeInfo| t.substitute_type_param(name, replacement);
+        let sub_box = |t: &TypeInfo| Box::new(sub(t));
+        match self {
+            Self::Ref(TypeRef {
+                referent:
+                    TypeName {
+                        name: TypeIdent::Name(n),
+                        ..
+                    },
+                type_params,
+            }) if n == name && type_params.is_empty() => replacement.clone(),
+            Self::Ref(TypeRef {
+                referent,
+                type_params,
+            }) => Self::Ref(TypeRef {
+                referent: referent.clone(),
+                type_params: type_params.iter().map(sub).collect(),
+            }),
+            Self::Array {
+                item_type,
+                readonly,
+            } => Self::Array {
+                item_type: sub_box(item_type),
+                readonly: *readonly,
+            },
+            Self::Optional { item_type } => Self::Optional {
+                item_type: sub_box(item_type),
+            },
+            Self::Mapped { value_type } => Self::Mapped {
+                value_type: sub_box(value_type),
+            },
+            Self::Union(Union { types }) => Self::Union(Union {
+                types: types.iter().map(sub).collect(),
+            }),
+            Self::Intersection(Intersection { types }) => Self::Intersection(Intersection {
+                types: types.iter().map(sub).collect(),
+            }),
+            Self::Tuple(Tuple {
+                types,
+                labels,
+                has_rest,
+            }) => Self::Tuple(Tuple {
+                types: types.iter().map(sub).collect(),
+                labels: labels.clone(),
+                has_rest: *has_rest,
+            }),
+            Self::Conditional(Conditional {
+                check_type,
+                extends_type,
+                true_type,
+                false_type,
+            }) => Self::Conditional(Conditional {
+                check_type: sub_box(check_type),
+                extends_type: sub_box(extends_type),
+                true_type: sub_box(true_type),
+                false_type: sub_box(false_type),
+            }),
+            Self::TypePredicate(TypePredicate {
+                param_name,
+                asserted_type,
+            }) => Self::TypePredicate(TypePredicate {
+                param_name: param_name.clone(),
+                asserted_type: sub_box(asserted_type),
+            }),
+            Self::TemplateLiteral(TemplateLiteral { quasis, types }) => {
+                Self::TemplateLiteral(TemplateLiteral {
+                    quasis: quasis.clone(),
+                    types: types.iter().map(sub).collect(),
+                })
+            }
+            Self::BuiltinAsyncIterable(BuiltinAsyncIterable { item_type }) => {
+                Self::BuiltinAsyncIterable(BuiltinAsyncIterable {
+                    item_type: sub_box(item_type),
+                })
+            }
+            Self::KeyOf { target } => Self::KeyOf {
+                target: sub_box(target),
+            },
+            Self::IndexedAccess(IndexedAccess {
+                object_type,
+                index_type,
+            }) => Self::IndexedAccess(IndexedAccess {
+                object_type: sub_box(object_type),
+                index_type: sub_box(index_type),
+            }),
+            Self::FuncGroup(fg) => Self::FuncGroup(fg.substitute_type_param(name, replacement)),
+            Self::Constructor(ctor) => {
+                Self::Constructor(ctor.substitute_type_param(name, replacement))
+            }
+            // the interface's own type params shadow `name`
+            Self::Interface(iface) if !iface.type_params.iter().any(|(n, _)| n == name) => {
+                Self::Interface(Interface {
+                    indexer: iface.indexer.as_ref().map(|i| Indexer {
+                        readonly: i.readonly,
+                        type_info: sub_box(&i.type_info),
+                    }),
+                    fields: iface
+                        .fields
+                        .iter()
+                        .map(|(n, t)| (n.clone(), sub(t)))
+                        .collect(),
+                    constructor: iface
+                        .constructor
+                        .as_ref()
+                        .map(|c| c.substitute_type_param(name, replacement)),
+                    callable: iface
+                        .callable
+                        .as_ref()
+                        .map(|c| c.substitute_type_param(name, replacement)),
+                    iterator: iface.iterator.as_deref().map(sub_box),
+                    async_iterator: iface.async_iterator.as_deref().map(sub_box),
+                    ..iface.clone()
+                })
+            }
+            _ => self.clone(),
+        }
+    }
+}
+
+/// Nesting limit for structural assignability checks so that mutually
+/// recursive types can't send us into an infinite loop.
+const MAX_ASSIGNABILITY_DEPTH: usize = 8;
+
+/// Broad categories of types that are never assignable to one another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TypeKind {
+    Number,
+    String,
+    Boolean,
+    BigInt,
+    Null,
+    Undefined,
+    Object,
+}
+
+fn type_kind(typ: &TypeInfo) -> Option<TypeKind> {
+    match typ {
+        TypeInfo::PrimitiveNumber(_) | TypeInfo::LitNumber(_) => Some(TypeKind::Number),
+        TypeInfo::PrimitiveString(_) | TypeInfo::LitString(_) | TypeInfo::TemplateLiteral(_) => {
+            Some(TypeKind::String)
+        }
+        TypeInfo::PrimitiveBoolean(_) | TypeInfo::LitBoolean(_) | TypeInfo::TypePredicate(_) => {
+            Some(TypeKind::Boolean)
+        }
+        TypeInfo::PrimitiveBigInt(_) | TypeInfo::LitBigInt(_) => Some(TypeKind::BigInt),
+        TypeInfo::PrimitiveNull(_) => Some(TypeKind::Null),
+        TypeInfo::PrimitiveUndefined(_) | TypeInfo::PrimitiveVoid(_) => Some(TypeKind::Undefined),
+        TypeInfo::Interface(_)
+        | TypeInfo::Class(_)
+        | TypeInfo::PrimitiveObject(_)
+        | TypeInfo::BuiltinPromise(_)
+        | TypeInfo::BuiltinAsyncIterable(_)
+        | TypeInfo::Array { .. }
+        | TypeInfo::Tuple(_)
+        | TypeInfo::Mapped { .. }
+        | TypeInfo::FuncGroup(_)
+        | TypeInfo::Constructor(_) => Some(TypeKind::Object),
+        _ => None,
+    }
+}
+
+fn is_type_param_ref(typ: &TypeInfo, type_params: &HashMap<String, TypeParamConfig>) -> bool {
+    match typ {
+        TypeInfo::Ref(TypeRef {
+            referent:
+                TypeName {
+                    name: TypeIdent::Name(n),
+                    ..
+                },
+            ..
+        }) => type_params.contains_key(n),
+        _ => false,
+    }
+}
+
+/// Follow refs and aliases until we reach a structural type.
+fn deref_type(
+    types_by_name_by_file: &HashMap<PathBuf, HashMap<TypeIdent, Type>>,
+    typ: &TypeInfo,
+) -> Option<TypeInfo> {
+    match typ {
+        TypeInfo::Ref(tr) => lookup_type(types_by_name_by_file, &tr.referent).and_then(|t| {
+            deref_type(
+                types_by_name_by_file,
+                &t.info
+                    .resolve_names(types_by_name_by_file, &Default::default()),
+            )
+        }),
+        TypeInfo::Alias(Alias { target, .. }) => deref_type(types_by_name_by_file, target),
+        _ => Some(typ.clone()),
+    }
+}
+
+/// Collect the property types of an interface or class, including inherited ones.
+fn structural_fields(
+    types_by_name_by_file: &HashMap<PathBuf, HashMap<TypeIdent, Type>>,
+    typ: &TypeInfo,
+) -> Option<HashMap<String, TypeInfo>> {
+    match typ {
+        TypeInfo::Interface(iface) => Some(recursive_iface_fields(
+            types_by_name_by_file,
+            iface.extends.clone(),
+            iface.fields.clone(),
+        )),
+        TypeInfo::Class(class) => Some(recursive_class_fields(
+            types_by_name_by_file,
+            class.super_class.clone(),
+            class.implements.clone(),
+            class.members.clone(),
+        )),
+        _ => None,
+    }
+}
+
+/// Combine assignability results where every result must hold.
+fn all_assignable<I: IntoIterator<Item = Option<bool>>>(results: I) -> Option<bool> {
+    results
+        .into_iter()
+        .try_fold(Some(true), |acc, r| match r {
+            Some(false) => Err(()),
+            Some(true) => Ok(acc),
+            None => Ok(None),
+        })
+        .unwrap_or(Some(false))
+}
+
+/// Combine assignability results where any result may hold.
+fn any_assignable<I: IntoIterator<Item = Option<bool>>>(results: I) -> Option<bool> {
+    results
+        .into_iter()
+        .try_fold(Some(false), |acc, r| match r {
+            Some(true) => Err(()),
+            Some(false) => Ok(acc),
+            None => Ok(None),
+        })
+        .unwrap_or(Some(true))
+}
+
+/// Decide whether `src` is assignable to `target`, as required to evaluate
+/// a conditional type. Returns `None` if we can't decide, e.g. because one of
+/// the types refers to an unbound type parameter.
+fn is_assignable(
+    types_by_name_by_file: &HashMap<PathBuf, HashMap<TypeIdent, Type>>,
+    type_params: &HashMap<String, TypeParamConfig>,
+    src: &TypeInfo,
+    target: &TypeInfo,
+    depth: usize,
+) -> Option<bool> {
+    if depth > MAX_ASSIGNABILITY_DEPTH {
+        return None;
+    }
+
+    let recurse = |s: &TypeInfo, t: &TypeInfo| {
+        is_assignable(types_by_name_by_file, type_params, s, t, depth + 1)
+    };
+
+    if matches!(target, TypeInfo::PrimitiveAny(_)) {
+        return Some(true);
+    }
+
+    if matches!(src, TypeInfo::PrimitiveAny(_))
+        || is_type_param_ref(src, type_params)
+        || is_type_param_ref(target, type_params)
+    {
+        return None;
+    }
+
+    if let TypeInfo::Union(Union { types }) = src {
+        return all_assignable(types.iter().map(|s| recurse(s, target)));
+    }
+
+    if let TypeInfo::Union(Union { types }) = target {
+        return any_assignable(types.iter().map(|t| recurse(src, t)));
+    }
+
+    if let (TypeInfo::Ref(s), TypeInfo::Ref(t)) = (src, target) {
+        if s.referent == t.referent {
+            return Some(true);
+        }
+    }
+
+    let src = deref_type(types_by_name_by_file, src)?;
+    let target = deref_type(types_by_name_by_file, target)?;
+
+    match (&src, &target) {
+        (TypeInfo::LitString(s), TypeInfo::LitString(t)) => Some(s == t),
+        (TypeInfo::LitNumber(s), TypeInfo::LitNumber(t)) => Some(s == t),
+        (TypeInfo::LitBoolean(s), TypeInfo::LitBoolean(t)) => Some(s == t),
+        (TypeInfo::LitBigInt(s), TypeInfo::LitBigInt(t)) => Some(s == t),
+        (TypeInfo::PrimitiveString(_), TypeInfo::LitString(_))
+        | (TypeInfo::PrimitiveNumber(_), TypeInfo::LitNumber(_))
+        | (TypeInfo::PrimitiveBoolean(_), TypeInfo::LitBoolean(_))
+        | (TypeInfo::PrimitiveBigInt(_), TypeInfo::LitBigInt(_)) => Some(false),
+        (TypeInfo::Array { item_type: s, .. }, TypeInfo::Array { item_type: t, .. }) => {
+            recurse(s, t)
+        }
+        (TypeInfo::BuiltinPromise(s), TypeInfo::BuiltinPromise(t)) => {
+            recurse(&s.value_type, &t.value_type)
+        }
+        (TypeInfo::BuiltinAsyncIterable(s), TypeInfo::BuiltinAsyncIterable(t)) => {
+            recurse(&s.item_type, &t.item_type)
+        }
+        (TypeInfo::JsSysBuiltin(s), TypeInfo::JsSysBuiltin(t)) => Some(s == t),
+        (TypeInfo::WebSysBuiltin(s), TypeInfo::WebSysBuiltin(t)) => Some(s == t),
+        (_, TypeInfo::PrimitiveObject(_)) => type_kind(&src).map(|k| k == TypeKind::Object),
+        (TypeInfo::Interface(_), TypeInfo::Interface(_))
+        | (TypeInfo::Class(_), TypeInfo::Interface(_))
+        | (TypeInfo::Interface(_), TypeInfo::Class(_))
+        | (TypeInfo::Class(_), TypeInfo::Class(_)) => {
+            let src_fields = structural_fields(types_by_name_by_file, &src)?;
+            let target_fields = structural_fields(types_by_name_by_file, &target)?;
+            all_assignable(
+                target_fields
+                    .iter()
+                    .map(|(n, t)| match (src_fields.get(n), t) {
+                        (Some(s), t) => recurse(s, t),
+                        (None, TypeInfo::Optional { .. }) => Some(true),
+                        (None, _) => Some(false),
+                    }),
+            )
+        }
+        (_, TypeInfo::Optional { item_type }) => match &src {
+            TypeInfo::PrimitiveUndefined(_) => Some(true),
+            TypeInfo::Optional { item_type: s } => recurse(s, item_type),
+            _ => recurse(&src, item_type),
+        },
+        _ => match (type_kind(&src), type_kind(&target)) {
+            (Some(s), Some(t)) if s != t => Some(false),
+            (Some(s), Some(t)) if s != TypeKind::Object && s == t => Some(true),
+            _ => None,
+        },
+    }
+}
+
+/// Returns the file and name of the namespace aliased by `alias` (e.g. by
+/// `import NS = A.B`) if it aliases a namespace rather than a type.
+fn namespace_alias_target(
+    types_by_name_by_file: &HashMap<PathBuf, HashMap<TypeIdent, Type>>,
+    alias: &Alias,
+) -> Option<(PathBuf, Vec<String>)> {
+    let referent = match alias.target.as_ref() {
+        TypeInfo::Ref(TypeRef {
+            referent,
+            type_params,
+        }) if type_params.is_empty() => referent,
+        _ => return None,
+    };
+    let name_parts = match &referent.name {
+        TypeIdent::Name(n) => vec![n.clone()],
+        TypeIdent::QualifiedName(name_parts) => name_parts.clone(),
+        _ => return None,
+    };
+
+    if lookup_type(types_by_name_by_file, referent).is_some() {
+        return None;
+    }
+
+    // namespaces aren't types themselves but qualify the names of their members
+    let is_namespace = types_by_name_by_file
+        .get(&referent.file)
+        .map(|types_by_name| {
+            types_by_name.keys().any(|n| match n {
+                TypeIdent::QualifiedName(parts) => {
+                    parts.len() > name_parts.len() && parts.starts_with(&name_parts)
+                }
+                _ => false,
+            })
+        })
+        .unwrap_or(false);
+
+    if is_namespace {
+        Some((referent.file.clone(), name_parts))
+    } else {
+        None
+    }
+}
+
+/// If `import` binds a module (or a namespace within one), returns the file
+/// and name parts that names qualified by the import are relative to.
+/// `export =` modules qualify names by their assigned export, whose namespace
+/// merges with it (e.g. `import dep = require("dep")` then `dep.Inner`).
+fn namespace_import_target(
+    types_by_name_by_file: &HashMap<PathBuf, HashMap<TypeIdent, Type>>,
+    import: &NamespaceImport,
+) -> Option<(PathBuf, Vec<String>)> {
+    match import {
+        NamespaceImport::All { src } => Some((src.clone(), Default::default())),
+        NamespaceImport::Named { src, name } => Some((src.clone(), vec![name.clone()])),
+        NamespaceImport::Namespace { src, name_parts } => Some((src.clone(), name_parts.clone())),
+        NamespaceImport::Default { src } => {
+            let default_export = lookup_type(
+                types_by_name_by_file,
+                &TypeName::default_export_for(src.clone()),
+            )?;
+            match &default_export.info {
+                TypeInfo::Alias(Alias { target, .. }) => match target.as_ref() {
+                    TypeInfo::Ref(TypeRef { referent, .. }) => match &referent.name {
+                        TypeIdent::Name(n) => Some((referent.file.clone(), vec![n.clone()])),
+                        TypeIdent::QualifiedName(name_parts) => {
+                            Some((referent.file.clone(), name_parts.clone()))
+                        }
+                        _ => None,
+                    },
+                    _ => None,
+                },
+                _ => None,
+            }
         }
     }
 }
@@ -1293,6 +2780,40 @@ fn canonicalize_type(
 ) -> Option<TypeName> {
     lookup_type(types_by_name_by_file, referent)
         .map(|_| referent.clone()) // keep found types as-is
+        .or_else(|| {
+            // NS.C, where NS aliases namespace A.B (or imports a module), refers
+            // to A.B.C (or C within the module)
+            let name_parts = match &referent.name {
+                TypeIdent::QualifiedName(name_parts) if name_parts.len() > 1 => name_parts,
+                _ => return None,
+            };
+            let (src, ns_parts) = lookup_type(
+                types_by_name_by_file,
+                &TypeName::for_name(referent.file.clone(), &name_parts[0]),
+            )
+            .and_then(|t| match &t.info {
+                TypeInfo::Alias(a) => namespace_alias_target(types_by_name_by_file, a),
+                TypeInfo::NamespaceImport(import) => {
+                    namespace_import_target(types_by_name_by_file, import)
+                }
+                _ => None,
+            })?;
+            if src == referent.file && ns_parts.first() == name_parts.first() {
+                // a namespace can't alias itself
+                return None;
+            }
+
+            canonicalize_type(
+                types_by_name_by_file,
+                &TypeName::for_qualified_name(
+                    src,
+                    ns_parts
+                        .into_iter()
+                        .chain(name_parts[1..].iter().cloned())
+                        .collect(),
+                ),
+            )
+        })
         .or_else(|| {
             // if we don't find the type AND we have a parent type environment,
             // check the parent recursively
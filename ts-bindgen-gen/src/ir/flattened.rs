@@ -1,18 +1,20 @@
 use crate::ir::base::{
-    Alias as AliasIR, BaseClass as BaseClassIR, BuiltinPromise, Class as ClassIR, Ctor as CtorIR,
-    CtorGroup as CtorGroupIR, Enum as EnumIR, EnumMember as EnumMemberIR, Func as FuncIR,
-    FuncGroup as FuncGroupIR, Indexer as IndexerIR, Interface as InterfaceIR,
-    Intersection as IntersectionIR, JsSysBuiltin as JsSysBuiltinIR, LitBoolean, LitNumber,
-    LitString, Member as MemberIR, Param as ParamIR, PrimitiveAny, PrimitiveBigInt,
-    PrimitiveBoolean, PrimitiveNull, PrimitiveNumber, PrimitiveObject, PrimitiveString,
-    PrimitiveUndefined, PrimitiveVoid, Tuple as TupleIR, Type as TypeIR, TypeIdent as TypeIdentIR,
-    TypeInfo as TypeInfoIR, TypeName as TypeNameIR, TypeParamConfig as TypeParamConfigIR,
+    Alias as AliasIR, BaseClass as BaseClassIR, BuiltinAsyncIterable, BuiltinPromise,
+    Class as ClassIR, Ctor as CtorIR, CtorGroup as CtorGroupIR, Enum as EnumIR,
+    EnumMember as EnumMemberIR, Func as FuncIR, FuncGroup as FuncGroupIR, Indexer as IndexerIR,
+    Interface as InterfaceIR, Intersection as IntersectionIR, JsSysBuiltin as JsSysBuiltinIR,
+    LitBigInt, LitBoolean, LitNumber, LitString, Member as MemberIR, Param as ParamIR,
+    PolymorphicThis, PrimitiveAny, PrimitiveBigInt, PrimitiveBoolean, PrimitiveNull,
+    PrimitiveNumber, PrimitiveObject, PrimitiveString, PrimitiveUndefined, PrimitiveVoid,
+    Property as PropertyIR, TemplateLiteral as TemplateLiteralIR, Tuple as TupleIR, Type as TypeIR,
+    TypeIdent as TypeIdentIR, TypeInfo as TypeInfoIR, TypeName as TypeNameIR,
+    TypeParamConfig as TypeParamConfigIR, TypePredicate as TypePredicateIR,
     TypeQuery as TypeQueryIR, TypeRef as TypeRefIR, Union as UnionIR,
     WebSysBuiltin as WebSysBuiltinIR,
 };
-pub use crate::ir::base::{EnumValue, NamespaceImport};
+pub use crate::ir::base::{EnumValue, MemberKey, NamespaceImport};
 use enum_to_enum::WithEffects;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::iter;
 use std::iter::{Extend, FromIterator};
 use std::path::PathBuf;
@@ -213,6 +215,8 @@ pub enum NameableTypeInfo {
     Intersection(Intersection),
     Tuple(Tuple),
     Interface(Interface),
+    Enum(Enum),
+    TemplateLiteral(TemplateLiteral),
 }
 
 impl From<Namespaced<NameableTypeInfo>> for FlattenedTypeInfo {
@@ -222,6 +226,8 @@ impl From<Namespaced<NameableTypeInfo>> for FlattenedTypeInfo {
             NameableTypeInfo::Intersection(i) => FlattenedTypeInfo::Intersection(i),
             NameableTypeInfo::Tuple(t) => FlattenedTypeInfo::Tuple(t),
             NameableTypeInfo::Interface(i) => FlattenedTypeInfo::Interface(i),
+            NameableTypeInfo::Enum(e) => FlattenedTypeInfo::Enum(e),
+            NameableTypeInfo::TemplateLiteral(t) => FlattenedTypeInfo::TemplateLiteral(t),
         }
     }
 }
@@ -247,6 +253,7 @@ pub enum FlattenedTypeInfo {
     Var { type_info: Box<FlattenedTypeInfo> },
     NamespaceImport(NamespaceImport),
     TypeQuery(TypeQuery),
+    TemplateLiteral(TemplateLiteral),
 }
 
 macro_rules! tuple_match_convert {
@@ -282,19 +289,27 @@ impl From<Namespaced<TypeInfoIR>> for EffectContainer<FlattenedTypeInfo> {
                 TypeInfoIR::PrimitiveVoid(v) => tuple_match_convert!(ns, Ref(v)),
                 TypeInfoIR::PrimitiveUndefined(v) => tuple_match_convert!(ns, Ref(v)),
                 TypeInfoIR::PrimitiveNull(v) => tuple_match_convert!(ns, Ref(v)),
+                TypeInfoIR::PolymorphicThis(v) => tuple_match_convert!(ns, Ref(v)),
                 TypeInfoIR::BuiltinPromise(v) => tuple_match_convert!(ns, Ref(v)),
+                TypeInfoIR::BuiltinAsyncIterable(v) => tuple_match_convert!(ns, Ref(v)),
                 TypeInfoIR::LitNumber(v) => tuple_match_convert!(ns, Ref(v)),
                 TypeInfoIR::LitString(v) => tuple_match_convert!(ns, Ref(v)),
                 TypeInfoIR::LitBoolean(v) => tuple_match_convert!(ns, Ref(v)),
+                TypeInfoIR::LitBigInt(v) => tuple_match_convert!(ns, Ref(v)),
                 TypeInfoIR::WebSysBuiltin(b) => tuple_match_convert!(ns, Ref(b)),
                 TypeInfoIR::JsSysBuiltin(b) => tuple_match_convert!(ns, Ref(b)),
                 TypeInfoIR::Ref(v) => tuple_match_convert!(ns, Ref(v)),
-                TypeInfoIR::Array { item_type } => struct_match_convert!(ns, Array { item_type }),
+                TypeInfoIR::Array { item_type, .. } => {
+                    struct_match_convert!(ns, Array { item_type })
+                }
                 TypeInfoIR::Tuple(v) => tuple_match_convert!(ns, Tuple(v)),
                 TypeInfoIR::Optional { item_type } => {
                     struct_match_convert!(ns, Optional { item_type })
                 }
-                TypeInfoIR::Union(v) => tuple_match_convert!(ns, Union(v)),
+                TypeInfoIR::Union(v) => match lower_literal_union(v) {
+                    TypeInfoIR::Union(v) => tuple_match_convert!(ns, Union(v)),
+                    lowered => ns.in_ns(lowered).into(),
+                },
                 TypeInfoIR::Intersection(v) => tuple_match_convert!(ns, Intersection(v)),
                 TypeInfoIR::Mapped { value_type } => {
                     struct_match_convert!(ns, Mapped { value_type })
@@ -305,6 +320,20 @@ impl From<Namespaced<TypeInfoIR>> for EffectContainer<FlattenedTypeInfo> {
                 TypeInfoIR::Var { type_info } => struct_match_convert!(ns, Var { type_info }),
                 TypeInfoIR::NamespaceImport(v) => tuple_match_convert!(ns, NamespaceImport(v)),
                 TypeInfoIR::TypeQuery(v) => tuple_match_convert!(ns, TypeQuery(v)),
+                TypeInfoIR::Conditional(_) => {
+                    panic!("Conditional types should be evaluated during name resolution")
+                }
+                TypeInfoIR::MappedType(_) => {
+                    panic!("Mapped types should be expanded during name resolution")
+                }
+                TypeInfoIR::KeyOf { .. } => {
+                    panic!("keyof should be evaluated during name resolution")
+                }
+                TypeInfoIR::IndexedAccess(_) => {
+                    panic!("Indexed access types should be resolved during name resolution")
+                }
+                TypeInfoIR::TypePredicate(v) => tuple_match_convert!(ns, Ref(v)),
+                TypeInfoIR::TemplateLiteral(v) => tuple_match_convert!(ns, TemplateLiteral(v)),
             }
         })
     }
@@ -347,6 +376,9 @@ impl ApplyNames for FlattenedTypeInfo {
             FlattenedTypeInfo::TypeQuery(q) => {
                 FlattenedTypeInfo::TypeQuery(q.apply_names(names_by_id))
             }
+            FlattenedTypeInfo::TemplateLiteral(t) => {
+                FlattenedTypeInfo::TemplateLiteral(t.apply_names(names_by_id))
+            }
         }
     }
 }
@@ -356,7 +388,11 @@ pub struct Interface {
     pub indexer: Option<Indexer>,
     pub extends: Vec<TypeRef>,
     pub fields: HashMap<String, TypeRef>,
+    pub readonly_fields: HashSet<String>,
     pub constructor: Option<Ctor>,
+    pub callable: Option<FuncGroup>,
+    pub iterator: Option<TypeRef>,
+    pub async_iterator: Option<TypeRef>,
     pub type_params: Vec<(String, TypeParamConfig)>,
 }
 
@@ -374,7 +410,11 @@ impl ApplyNames for Interface {
                 .into_iter()
                 .map(|(k, v)| (k, v.apply_names(names_by_id)))
                 .collect(),
+            readonly_fields: self.readonly_fields,
             constructor: self.constructor.map(|i| i.apply_names(names_by_id)),
+            callable: self.callable.map(|c| c.apply_names(names_by_id)),
+            iterator: self.iterator.map(|t| t.apply_names(names_by_id)),
+            async_iterator: self.async_iterator.map(|t| t.apply_names(names_by_id)),
             type_params: self
                 .type_params
                 .into_iter()
@@ -466,6 +506,21 @@ impl From<Namespaced<InterfaceIR>> for EffectContainer<Interface> {
                 .map(|c| ns.in_ns(c))
                 .map(EffectContainer::from)
                 .into();
+            let callable = v
+                .callable
+                .map(|c| ns.in_ns(c))
+                .map(EffectContainer::from)
+                .into();
+            let iterator = v
+                .iterator
+                .map(|t| ns.in_ns(*t))
+                .map(EffectContainer::from)
+                .into();
+            let async_iterator = v
+                .async_iterator
+                .map(|t| ns.in_ns(*t))
+                .map(EffectContainer::from)
+                .into();
             let type_params = v
                 .type_params
                 .into_iter()
@@ -481,12 +536,19 @@ impl From<Namespaced<InterfaceIR>> for EffectContainer<Interface> {
                 extends => (effect_mappers::identity()),
                 fields => (effect_mappers::identity()),
                 constructor => (effect_mappers::prepend_name("Ctor")),
+                callable => (effect_mappers::prepend_name("Call")),
+                iterator => (effect_mappers::prepend_name("Item")),
+                async_iterator => (effect_mappers::prepend_name("AsyncItem")),
                 type_params => (effect_mappers::identity());
                 Interface {
                     indexer,
                     extends,
                     fields,
+                    readonly_fields: v.readonly_fields,
                     constructor,
+                    callable,
+                    iterator,
+                    async_iterator,
                     type_params,
                 }
             )
@@ -608,7 +670,7 @@ impl From<Namespaced<TypeInfoIR>> for EffectContainer<TypeRef> {
     fn from(src: Namespaced<TypeInfoIR>) -> EffectContainer<TypeRef> {
         src.map(|v, ns| match v {
             TypeInfoIR::Interface(i) => ns.in_ns(i).into(),
-            TypeInfoIR::Enum(_) => panic!("Enum only expected as top-level type"),
+            TypeInfoIR::Enum(e) => ns.in_ns(e).into(),
             TypeInfoIR::Ref(t) => ns.in_ns(t).into(),
             TypeInfoIR::Alias(_) => panic!("Alias only expected as top-level type"),
             TypeInfoIR::PrimitiveAny(p) => ns.in_ns(p).into(),
@@ -620,15 +682,25 @@ impl From<Namespaced<TypeInfoIR>> for EffectContainer<TypeRef> {
             TypeInfoIR::PrimitiveVoid(p) => ns.in_ns(p).into(),
             TypeInfoIR::PrimitiveUndefined(p) => ns.in_ns(p).into(),
             TypeInfoIR::PrimitiveNull(p) => ns.in_ns(p).into(),
+            TypeInfoIR::PolymorphicThis(p) => ns.in_ns(p).into(),
             TypeInfoIR::BuiltinPromise(b) => ns.in_ns(b).into(),
+            TypeInfoIR::BuiltinAsyncIterable(b) => ns.in_ns(b).into(),
             TypeInfoIR::WebSysBuiltin(b) => ns.in_ns(b).into(),
             TypeInfoIR::JsSysBuiltin(b) => ns.in_ns(b).into(),
-            TypeInfoIR::Array { item_type } => {
+            TypeInfoIR::Array {
+                item_type,
+                readonly,
+            } => {
                 let item_type: EffectContainer<TypeRef> = ns.in_ns(*item_type).into();
+                let builtin = if readonly {
+                    Builtin::ReadonlyArray
+                } else {
+                    Builtin::Array
+                };
                 combine_effects!(
                     item_type => (effect_mappers::identity());
                     TypeRef {
-                        referent: TypeIdent::Builtin(Builtin::Array),
+                        referent: TypeIdent::Builtin(builtin),
                         type_params: vec![item_type],
                     }
                 )
@@ -644,7 +716,10 @@ impl From<Namespaced<TypeInfoIR>> for EffectContainer<TypeRef> {
                     }
                 )
             }
-            TypeInfoIR::Union(u) => ns.in_ns(u).into(),
+            TypeInfoIR::Union(u) => match lower_literal_union(u) {
+                TypeInfoIR::Union(u) => ns.in_ns(u).into(),
+                lowered => ns.in_ns(lowered).into(),
+            },
             TypeInfoIR::Intersection(i) => ns.in_ns(i).into(),
             TypeInfoIR::Mapped { value_type } => {
                 let value_type: EffectContainer<TypeRef> = ns.in_ns(*value_type).into();
@@ -665,6 +740,7 @@ impl From<Namespaced<TypeInfoIR>> for EffectContainer<TypeRef> {
             TypeInfoIR::LitNumber(l) => ns.in_ns(l).into(),
             TypeInfoIR::LitString(l) => ns.in_ns(l).into(),
             TypeInfoIR::LitBoolean(l) => ns.in_ns(l).into(),
+            TypeInfoIR::LitBigInt(l) => ns.in_ns(l).into(),
             TypeInfoIR::FuncGroup(f) => ns.in_ns(f).into(),
             TypeInfoIR::Constructor(_) => panic!("Constructor only expected as top-level type"),
             TypeInfoIR::Class(_) => panic!("Class only expected as top-level type"),
@@ -673,6 +749,18 @@ impl From<Namespaced<TypeInfoIR>> for EffectContainer<TypeRef> {
                 panic!("Namespace import only expected as a top-level construct")
             }
             TypeInfoIR::TypeQuery(tr) => ns.in_ns(tr).into(),
+            TypeInfoIR::Conditional(_) => {
+                panic!("Conditional types should be evaluated during name resolution")
+            }
+            TypeInfoIR::MappedType(_) => {
+                panic!("Mapped types should be expanded during name resolution")
+            }
+            TypeInfoIR::KeyOf { .. } => panic!("keyof should be evaluated during name resolution"),
+            TypeInfoIR::IndexedAccess(_) => {
+                panic!("Indexed access types should be resolved during name resolution")
+            }
+            TypeInfoIR::TypePredicate(p) => ns.in_ns(p).into(),
+            TypeInfoIR::TemplateLiteral(t) => ns.in_ns(t).into(),
         })
     }
 }
@@ -824,6 +912,8 @@ impl_effectful_conversion_from_nameable_type_to_type_ref!(
     IntersectionIR => Intersection,
     TupleIR => Tuple,
     InterfaceIR => Interface,
+    EnumIR => Enum,
+    TemplateLiteralIR => TemplateLiteral,
 );
 
 macro_rules! type_ref_from_prims {
@@ -856,11 +946,16 @@ pub enum Builtin {
     PrimitiveVoid,
     PrimitiveUndefined,
     PrimitiveNull,
+    PolymorphicThis,
     LitNumber,
     LitBoolean,
     LitString,
     Promise,
+    AsyncIterable,
+    /// `param is T`, a boolean indicating whether the named parameter is a `T`
+    TypePredicate(String),
     Array,
+    ReadonlyArray,
     Fn,
     Map,
     Optional,
@@ -878,12 +973,46 @@ type_ref_from_prims!(
     PrimitiveVoid => PrimitiveVoid,
     PrimitiveUndefined => PrimitiveUndefined,
     PrimitiveNull => PrimitiveNull,
+    PolymorphicThis => PolymorphicThis,
     LitNumber => LitNumber,
     LitBoolean => LitBoolean,
     LitString => LitString,
+    // bigint literals can't be enum discriminants or const generics so we
+    // only keep track of their type
+    LitBigInt => PrimitiveBigInt,
     BuiltinPromise => Promise,
 );
 
+impl From<Namespaced<BuiltinAsyncIterable>> for EffectContainer<TypeRef> {
+    fn from(src: Namespaced<BuiltinAsyncIterable>) -> EffectContainer<TypeRef> {
+        src.map(|v, ns| {
+            let item_type: EffectContainer<TypeRef> = ns.in_ns(*v.item_type).into();
+            combine_effects!(
+                item_type => (effect_mappers::identity());
+                TypeRef {
+                    referent: TypeIdent::Builtin(Builtin::AsyncIterable),
+                    type_params: vec![item_type],
+                }
+            )
+        })
+    }
+}
+
+impl From<Namespaced<TypePredicateIR>> for EffectContainer<TypeRef> {
+    fn from(src: Namespaced<TypePredicateIR>) -> EffectContainer<TypeRef> {
+        src.map(|v, ns| {
+            let asserted_type: EffectContainer<TypeRef> = ns.in_ns(*v.asserted_type).into();
+            combine_effects!(
+                asserted_type => (effect_mappers::identity());
+                TypeRef {
+                    referent: TypeIdent::Builtin(Builtin::TypePredicate(v.param_name)),
+                    type_params: vec![asserted_type],
+                }
+            )
+        })
+    }
+}
+
 impl From<Namespaced<WebSysBuiltinIR>> for EffectContainer<TypeRef> {
     fn from(src: Namespaced<WebSysBuiltinIR>) -> EffectContainer<TypeRef> {
         EffectContainer::new(
@@ -1009,6 +1138,8 @@ impl From<Namespaced<IntersectionIR>> for EffectContainer<Intersection> {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Tuple {
     pub types: Vec<TypeRef>,
+    pub labels: Vec<String>,
+    pub has_rest: bool,
 }
 
 impl ApplyNames for Tuple {
@@ -1019,6 +1150,8 @@ impl ApplyNames for Tuple {
                 .into_iter()
                 .map(|t| t.apply_names(names_by_id))
                 .collect(),
+            labels: self.labels,
+            has_rest: self.has_rest,
         }
     }
 }
@@ -1043,6 +1176,8 @@ impl From<Namespaced<TupleIR>> for EffectContainer<Tuple> {
                 types => (effect_mappers::prepend_name("Tuple"));
                 Tuple {
                     types,
+                    labels: v.labels,
+                    has_rest: v.has_rest,
                 }
             )
         })
@@ -1103,6 +1238,96 @@ impl ApplyNames for FuncGroup {
     }
 }
 
+/// Unions consisting solely of literals (e.g. `"left" | "right"`) are lowered to
+/// fieldless enums. Any null or undefined members are kept in a union alongside the
+/// enum.
+fn lower_literal_union(mut u: UnionIR) -> TypeInfoIR {
+    // we represent bigint literals as bigints so a union of them is just a bigint
+    if u.types
+        .iter()
+        .any(|t| matches!(t, TypeInfoIR::LitBigInt(_)))
+    {
+        u.types
+            .retain(|t| !matches!(t, TypeInfoIR::LitBigInt(_) | TypeInfoIR::PrimitiveBigInt(_)));
+        if u.types.is_empty() {
+            return TypeInfoIR::PrimitiveBigInt(PrimitiveBigInt());
+        }
+        u.types.push(TypeInfoIR::PrimitiveBigInt(PrimitiveBigInt()));
+    }
+
+    let is_nullish = |t: &TypeInfoIR| {
+        matches!(
+            t,
+            TypeInfoIR::PrimitiveNull(_) | TypeInfoIR::PrimitiveUndefined(_)
+        )
+    };
+    let is_literal = |t: &TypeInfoIR| {
+        matches!(
+            t,
+            TypeInfoIR::LitString(_) | TypeInfoIR::LitNumber(_) | TypeInfoIR::LitBoolean(_)
+        )
+    };
+
+    let has_literals = u.types.iter().any(is_literal);
+    if !has_literals || !u.types.iter().all(|t| is_literal(t) || is_nullish(t)) {
+        return TypeInfoIR::Union(u);
+    }
+
+    let (nullish, literals): (Vec<_>, Vec<_>) = u.types.into_iter().partition(is_nullish);
+    // the integer and fractional parts of numbers are separated by an
+    // underscore so that e.g. -1.5 and -15 get distinct names
+    let number_name = |n: &str| n.replace('.', "_");
+    let members = literals
+        .into_iter()
+        .filter_map(|t| match t {
+            TypeInfoIR::LitString(LitString { s }) => Some(EnumMemberIR {
+                id: if s.is_empty() {
+                    "Empty".to_string()
+                } else if s.starts_with(|c: char| c.is_ascii_digit()) {
+                    // rust identifiers can't start with digits
+                    format!("N{}", number_name(&s))
+                } else if let Some(n) = s
+                    .strip_prefix('-')
+                    .filter(|n| n.starts_with(|c: char| c.is_ascii_digit()))
+                {
+                    format!("Neg{}", number_name(n))
+                } else {
+                    s.clone()
+                },
+                value: Some(EnumValue::Str(s)),
+            }),
+            TypeInfoIR::LitNumber(LitNumber { n }) => Some(EnumMemberIR {
+                id: if n < 0.0 {
+                    format!("Neg{}", number_name(&(-n).to_string()))
+                } else {
+                    format!("N{}", number_name(&n.to_string()))
+                },
+                value: Some(EnumValue::Num(n)),
+            }),
+            TypeInfoIR::LitBoolean(LitBoolean { b }) => Some(EnumMemberIR {
+                id: b.to_string(),
+                value: Some(EnumValue::Bool(b)),
+            }),
+            _ => None,
+        })
+        .fold(Vec::new(), |mut members: Vec<EnumMemberIR>, m| {
+            // de-dupe repeated literals
+            if !members.iter().any(|existing| existing.value == m.value) {
+                members.push(m);
+            }
+            members
+        });
+    let enm = TypeInfoIR::Enum(EnumIR { members });
+
+    if nullish.is_empty() {
+        enm
+    } else {
+        TypeInfoIR::Union(UnionIR {
+            types: iter::once(enm).chain(nullish).collect(),
+        })
+    }
+}
+
 fn combine_to_union(a: &TypeInfoIR, b: &TypeInfoIR) -> TypeInfoIR {
     match (a, b) {
         (TypeInfoIR::Union(u1), TypeInfoIR::Union(u2)) => TypeInfoIR::Union(UnionIR {
@@ -1358,7 +1583,7 @@ impl From<Namespaced<CtorIR>> for EffectContainer<Ctor> {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Class {
     pub super_class: Option<TypeRef>,
-    pub members: HashMap<String, Member>,
+    pub members: HashMap<MemberKey, Member>,
     pub type_params: Vec<(String, TypeParamConfig)>,
     pub implements: Vec<TypeRef>,
 }
@@ -1410,12 +1635,24 @@ impl From<Namespaced<ClassIR>> for EffectContainer<Class> {
                     // aren't, we may need to find a way to force them to be exported
                     // (reasonable since they are de facto exported by virtue of being a property on
                     // an exported class)
-                    !matches!(m, MemberIR::Property(TypeInfoIR::TypeQuery(_)))
+                    !matches!(
+                        m,
+                        MemberIR::Property(PropertyIR {
+                            type_info: TypeInfoIR::TypeQuery(_),
+                            ..
+                        })
+                    )
                 })
-                .map(|(n, m)| {
+                .map(|(key, m)| {
+                    // statics may share their name with an instance member so we
+                    // keep the names of the types generated for them apart
+                    let prefix = match &key {
+                        MemberKey::Instance(n) => n.clone(),
+                        MemberKey::Static(n) => format!("static_{}", n),
+                    };
                     let effects = EffectContainer::from(ns.in_ns(m))
-                        .adapt_effects(effect_mappers::prepend_name(&n));
-                    (n, effects)
+                        .adapt_effects(effect_mappers::prepend_name(prefix));
+                    (key, effects)
                 })
                 .collect();
             let implements = v
@@ -1443,7 +1680,11 @@ impl From<Namespaced<ClassIR>> for EffectContainer<Class> {
 pub enum Member {
     Constructor(CtorGroup),
     Method(FuncGroup),
-    Property(TypeRef),
+    Property(Property),
+    StaticMethod(FuncGroup),
+    StaticProperty(Property),
+    Iterator(TypeRef),
+    AsyncIterator(TypeRef),
 }
 
 impl ApplyNames for Member {
@@ -1452,6 +1693,10 @@ impl ApplyNames for Member {
             Member::Constructor(c) => Member::Constructor(c.apply_names(names_by_id)),
             Member::Method(c) => Member::Method(c.apply_names(names_by_id)),
             Member::Property(c) => Member::Property(c.apply_names(names_by_id)),
+            Member::StaticMethod(c) => Member::StaticMethod(c.apply_names(names_by_id)),
+            Member::StaticProperty(c) => Member::StaticProperty(c.apply_names(names_by_id)),
+            Member::Iterator(c) => Member::Iterator(c.apply_names(names_by_id)),
+            Member::AsyncIterator(c) => Member::AsyncIterator(c.apply_names(names_by_id)),
         }
     }
 }
@@ -1480,6 +1725,65 @@ impl From<Namespaced<MemberIR>> for EffectContainer<Member> {
                     Member::Property(t)
                 )
             }
+            MemberIR::StaticMethod(f) => {
+                let f = ns.in_ns(f).into();
+                combine_effects!(
+                    f => (effect_mappers::identity());
+                    Member::StaticMethod(f)
+                )
+            }
+            MemberIR::StaticProperty(t) => {
+                let t = ns.in_ns(t).into();
+                combine_effects!(
+                    t => (effect_mappers::identity());
+                    Member::StaticProperty(t)
+                )
+            }
+            MemberIR::Iterator(t) => {
+                let t = ns.in_ns(t).into();
+                combine_effects!(
+                    t => (effect_mappers::identity());
+                    Member::Iterator(t)
+                )
+            }
+            MemberIR::AsyncIterator(t) => {
+                let t = ns.in_ns(t).into();
+                combine_effects!(
+                    t => (effect_mappers::identity());
+                    Member::AsyncIterator(t)
+                )
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Property {
+    pub readonly: bool,
+    pub type_info: TypeRef,
+}
+
+impl ApplyNames for Property {
+    fn apply_names(self, names_by_id: &HashMap<usize, TypeIdent>) -> Self {
+        Property {
+            readonly: self.readonly,
+            type_info: self.type_info.apply_names(names_by_id),
+        }
+    }
+}
+
+impl From<Namespaced<PropertyIR>> for EffectContainer<Property> {
+    fn from(src: Namespaced<PropertyIR>) -> EffectContainer<Property> {
+        src.map(|v, ns| {
+            let type_info = ns.in_ns(v.type_info).into();
+
+            combine_effects!(
+                type_info => (effect_mappers::identity());
+                Property {
+                    readonly: v.readonly,
+                    type_info,
+                }
+            )
         })
     }
 }
@@ -1523,6 +1827,66 @@ impl From<Namespaced<EnumIR>> for Enum {
     }
 }
 
+/// What may fill a hole, `${...}`, of a [`TemplateLiteral`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateHole {
+    /// Exactly one of the provided strings
+    OneOf(Vec<String>),
+    String,
+    Number,
+    BigInt,
+}
+
+/// A template literal type that could not be expanded into a union of literals.
+/// `quasis` are the literal strings surrounding each of the `holes`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateLiteral {
+    pub quasis: Vec<String>,
+    pub holes: Vec<TemplateHole>,
+}
+
+impl ApplyNames for TemplateLiteral {
+    fn apply_names(self, _: &HashMap<usize, TypeIdent>) -> Self {
+        self
+    }
+}
+
+impl From<Namespaced<TemplateLiteralIR>> for TemplateLiteral {
+    fn from(src: Namespaced<TemplateLiteralIR>) -> TemplateLiteral {
+        let hole_literal = |t: &TypeInfoIR| match t {
+            TypeInfoIR::LitString(LitString { s }) => Some(s.clone()),
+            _ => None,
+        };
+
+        TemplateLiteral {
+            quasis: src.value.quasis,
+            holes: src
+                .value
+                .types
+                .iter()
+                .map(|t| match t {
+                    // finite holes are narrowed to (unions of) string literals
+                    // during name resolution
+                    TypeInfoIR::LitString(LitString { s }) => TemplateHole::OneOf(vec![s.clone()]),
+                    TypeInfoIR::Union(UnionIR { types }) => types
+                        .iter()
+                        .map(hole_literal)
+                        .collect::<Option<_>>()
+                        .map(TemplateHole::OneOf)
+                        .unwrap_or(TemplateHole::String),
+                    TypeInfoIR::PrimitiveNumber(_) | TypeInfoIR::LitNumber(_) => {
+                        TemplateHole::Number
+                    }
+                    TypeInfoIR::PrimitiveBigInt(_) => TemplateHole::BigInt,
+                    // anything else (string, type params, nested templates, etc.)
+                    // we can only treat as an arbitrary string
+                    _ => TemplateHole::String,
+                })
+                .collect(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Alias {
     pub target: TypeRef,
@@ -1618,6 +1982,7 @@ impl_effectless_conversion!(
     NamespaceImport => NamespaceImport,
     EnumIR => Enum,
     EnumMemberIR => EnumMember,
+    TemplateLiteralIR => TemplateLiteral,
 );
 
 pub fn flatten_types<Ts: IntoIterator<Item = TypeIR>>(types: Ts) -> impl Iterator<Item = FlatType> {
@@ -6,16 +6,17 @@ use crate::ir::flattened::{
     FlatType, FlattenedTypeInfo, Func as FlattenedFunc, FuncGroup as FlattenedFuncGroup,
     Indexer as FlattenedIndexer, Interface as FlattenedInterface,
     Intersection as FlattenedIntersection, Member as FlattenedMember,
-    NamespaceImport as FlattenedNamespaceImport, Param as FlattenedParam, Tuple as FlattenedTuple,
-    TypeParamConfig as FlattenedTypeParamConfig, TypeQuery as FlattenedTypeQuery,
-    TypeRef as FlattenedTypeRef, Union as FlattenedUnion,
+    NamespaceImport as FlattenedNamespaceImport, Param as FlattenedParam,
+    Property as FlattenedProperty, TemplateLiteral as FlattenedTemplateLiteral,
+    Tuple as FlattenedTuple, TypeParamConfig as FlattenedTypeParamConfig,
+    TypeQuery as FlattenedTypeQuery, TypeRef as FlattenedTypeRef, Union as FlattenedUnion,
 };
-pub use crate::ir::flattened::{Builtin, EnumValue, TypeIdent};
+pub use crate::ir::flattened::{Builtin, EnumValue, MemberKey, TemplateHole, TypeIdent};
 use crate::mod_def::ToModPathIter;
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 use std::sync::Arc;
 use strum_macros::Display as StrumDisplay;
@@ -165,6 +166,7 @@ pub enum TargetEnrichedTypeInfo {
     },
     NamespaceImport(NamespaceImport),
     TypeQuery(TypeQuery),
+    TemplateLiteral(TemplateLiteral),
 }
 
 macro_rules! case_conv {
@@ -241,6 +243,9 @@ impl From<WithContext<FlattenedTypeInfo>> for TargetEnrichedTypeInfo {
             case_conv!(match FlattenedTypeInfo::TypeQuery, x) => {
                 case_conv!(TargetEnrichedTypeInfo::TypeQuery, x, ctx)
             }
+            case_conv!(match FlattenedTypeInfo::TemplateLiteral, x) => {
+                case_conv!(TargetEnrichedTypeInfo::TemplateLiteral, x, ctx)
+            }
         }
     }
 }
@@ -249,9 +254,26 @@ impl From<WithContext<FlattenedTypeInfo>> for TargetEnrichedTypeInfo {
 pub enum Member {
     Constructor(CtorGroup),
     Method(FuncGroup),
-    Property(TypeRef),
+    Property(Property),
+    StaticMethod(FuncGroup),
+    StaticProperty(Property),
+    Iterator(TypeRef),
+    AsyncIterator(TypeRef),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Property {
+    pub readonly: bool,
+    pub type_info: TypeRef,
+    pub context: Context,
 }
 
+from_struct!(
+    FlattenedProperty => Property;
+    readonly => nc,
+    type_info => .,
+);
+
 impl From<WithContext<FlattenedMember>> for Member {
     fn from(src: WithContext<FlattenedMember>) -> Member {
         let value = src.value;
@@ -267,6 +289,18 @@ impl From<WithContext<FlattenedMember>> for Member {
             case_conv!(match FlattenedMember::Property, x) => {
                 case_conv!(Member::Property, x, ctx)
             }
+            case_conv!(match FlattenedMember::StaticMethod, x) => {
+                case_conv!(Member::StaticMethod, x, ctx)
+            }
+            case_conv!(match FlattenedMember::StaticProperty, x) => {
+                case_conv!(Member::StaticProperty, x, ctx)
+            }
+            case_conv!(match FlattenedMember::Iterator, x) => {
+                case_conv!(Member::Iterator, x, ctx)
+            }
+            case_conv!(match FlattenedMember::AsyncIterator, x) => {
+                case_conv!(Member::AsyncIterator, x, ctx)
+            }
         }
     }
 }
@@ -274,7 +308,7 @@ impl From<WithContext<FlattenedMember>> for Member {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Class {
     pub super_class: Option<TypeRef>,
-    pub members: HashMap<String, Member>,
+    pub members: HashMap<MemberKey, Member>,
     pub type_params: Vec<(String, TypeParamConfig)>,
     pub implements: Vec<TypeRef>,
     pub context: Context,
@@ -357,6 +391,8 @@ pub struct Func {
     pub params: Vec<Param>,
     pub return_type: Box<TypeRef>,
     pub class_name: Option<TypeIdent>,
+    /// For type guards, the parameter checked by our boolean return type
+    pub type_guard: Option<TypeGuard>,
     pub context: Context,
 }
 
@@ -366,13 +402,39 @@ impl Func {
     }
 }
 
-from_struct!(
-    FlattenedFunc => Func;
-    type_params => {},
-    params => [],
-    return_type => box,
-    class_name => nc,
-);
+impl From<WithContext<FlattenedFunc>> for Func {
+    fn from(src: WithContext<FlattenedFunc>) -> Func {
+        let value = src.value;
+        let ctx = &src.context;
+        let type_guard = match &value.return_type.referent {
+            TypeIdent::Builtin(Builtin::TypePredicate(param_name)) => value
+                .return_type
+                .type_params
+                .first()
+                .map(|asserted_type| TypeGuard {
+                    param_name: param_name.clone(),
+                    asserted_type: ctx.wrap(asserted_type.clone()).into(),
+                }),
+            _ => None,
+        };
+
+        Func {
+            context: ctx.clone(),
+            type_params: from_field!(value, ctx, type_params, {}),
+            params: from_field!(value, ctx, params, []),
+            return_type: from_field!(value, ctx, return_type, box),
+            class_name: from_field!(value, ctx, class_name, nc),
+            type_guard,
+        }
+    }
+}
+
+/// A type guard, `(param_name: T) => param_name is asserted_type`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeGuard {
+    pub param_name: String,
+    pub asserted_type: TypeRef,
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Union {
@@ -388,12 +450,16 @@ from_struct!(
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Tuple {
     pub types: Vec<TypeRef>,
+    pub labels: Vec<String>,
+    pub has_rest: bool,
     pub context: Context,
 }
 
 from_struct!(
     FlattenedTuple => Tuple;
     types => [],
+    labels => nc,
+    has_rest => nc,
 );
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -444,6 +510,19 @@ from_struct!(
     value => nc,
 );
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateLiteral {
+    pub quasis: Vec<String>,
+    pub holes: Vec<TemplateHole>,
+    pub context: Context,
+}
+
+from_struct!(
+    FlattenedTemplateLiteral => TemplateLiteral;
+    quasis => nc,
+    holes => nc,
+);
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Indexer {
     pub readonly: bool,
@@ -462,7 +541,11 @@ pub struct Interface {
     pub indexer: Option<Indexer>,
     pub extends: Vec<TypeRef>,
     pub fields: HashMap<String, TypeRef>,
+    pub readonly_fields: HashSet<String>,
     pub constructor: Option<Ctor>,
+    pub callable: Option<FuncGroup>,
+    pub iterator: Option<TypeRef>,
+    pub async_iterator: Option<TypeRef>,
     pub type_params: Vec<(String, TypeParamConfig)>,
     pub context: Context,
 }
@@ -472,7 +555,11 @@ from_struct!(
     indexer => Option,
     extends => [],
     fields => {},
+    readonly_fields => nc,
     constructor => Option,
+    callable => Option,
+    iterator => Option,
+    async_iterator => Option,
     type_params => {},
 );
 
@@ -512,11 +599,26 @@ impl std::cmp::PartialEq for TypeRef {
     }
 }
 
-from_struct!(
-    FlattenedTypeRef => TypeRef;
-    referent => nc,
-    type_params => [],
-);
+impl From<WithContext<FlattenedTypeRef>> for TypeRef {
+    fn from(src: WithContext<FlattenedTypeRef>) -> TypeRef {
+        let value = src.value;
+        let ctx = &src.context;
+        match value.referent {
+            // rust sees type predicates as plain booleans. functions keep the
+            // predicate as their `type_guard`
+            TypeIdent::Builtin(Builtin::TypePredicate(_)) => TypeRef {
+                referent: TypeIdent::Builtin(Builtin::PrimitiveBoolean),
+                type_params: Default::default(),
+                context: ctx.clone(),
+            },
+            _ => TypeRef {
+                context: ctx.clone(),
+                referent: from_field!(value, ctx, referent, nc),
+                type_params: from_field!(value, ctx, type_params, []),
+            },
+        }
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NamespaceImport {
@@ -533,6 +635,11 @@ pub enum NamespaceImport {
         name: String,
         context: Context,
     },
+    Namespace {
+        src: PathBuf,
+        name_parts: Vec<String>,
+        context: Context,
+    },
 }
 
 impl From<WithContext<FlattenedNamespaceImport>> for NamespaceImport {
@@ -545,6 +652,11 @@ impl From<WithContext<FlattenedNamespaceImport>> for NamespaceImport {
             FlattenedNamespaceImport::Named { src, name } => {
                 NamespaceImport::Named { src, name, context }
             }
+            FlattenedNamespaceImport::Namespace { src, name_parts } => NamespaceImport::Namespace {
+                src,
+                name_parts,
+                context,
+            },
         }
     }
 }
@@ -567,11 +679,80 @@ impl From<WithContext<FlattenedTypeQuery>> for TypeQuery {
     }
 }
 
+/// Describes where the javascript implementing a typescript file lives
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsImport {
+    /// The js module alongside the typescript file
+    Module,
+    /// Globals, within the given (possibly empty) js namespace path (e.g. for
+    /// UMD libraries loaded via a script tag or `declare global` blocks)
+    Global { js_namespace: Vec<String> },
+}
+
+/// Where the javascript for each file lives, along with any types in those
+/// files whose javascript lives elsewhere
+#[derive(Debug, Clone, Default)]
+pub struct JsImports {
+    pub by_file: HashMap<PathBuf, JsImport>,
+    /// Imports of individual types, by file and type name parts
+    pub by_name_by_file: HashMap<PathBuf, HashMap<Vec<String>, JsImport>>,
+}
+
+impl JsImports {
+    fn js_import_for(&self, path: &Path, name: &TypeIdent) -> JsImport {
+        let name_parts = match name {
+            TypeIdent::Name { name, .. } => Some(vec![name.clone()]),
+            TypeIdent::QualifiedName { name_parts, .. } => Some(name_parts.clone()),
+            _ => None,
+        };
+
+        name_parts
+            .and_then(|name_parts| {
+                self.by_name_by_file
+                    .get(path)
+                    .and_then(|by_name| by_name.get(&name_parts))
+            })
+            .or_else(|| self.by_file.get(path))
+            .cloned()
+            .unwrap_or(JsImport::Module)
+    }
+}
+
+/// Determines the rust type that typescript `bigint`s are represented as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BigIntMapping {
+    /// `js_sys::BigInt`, which can hold any bigint
+    JsBigInt,
+    /// `i64`, failing to deserialize bigints outside of its range
+    I64,
+    /// `u64`, failing to deserialize bigints outside of its range
+    U64,
+    /// `i128`, failing to deserialize bigints outside of its range
+    I128,
+}
+
+impl Default for BigIntMapping {
+    fn default() -> Self {
+        BigIntMapping::JsBigInt
+    }
+}
+
+/// Options determining the rust types that we represent typescript types as.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TypeOptions {
+    pub bigint_mapping: BigIntMapping,
+    /// Whether async iterables are represented as `ts_bindgen_rt::AsyncIterStream`s,
+    /// which requires the `futures` feature of ts-bindgen-rt, rather than `JsValue`s
+    pub async_iter_streams: bool,
+}
+
 #[derive(Clone)]
 pub struct Context {
     pub types_by_ident_by_path: WrappedTypesByIdentByPath,
     pub path: PathBuf,
     pub base_namespace: Vec<Identifier>,
+    pub js_import: JsImport,
+    pub type_options: TypeOptions,
     // TODO: no reason for this to be an Arc but we always have an Arc when we
     // want to use this and adding a lifetime to Context poisons all target
     // enriched types, which is a huge pain.
@@ -595,6 +776,8 @@ impl Context {
             types_by_ident_by_path: Default::default(),
             path: PathBuf::new(),
             base_namespace: Default::default(),
+            js_import: JsImport::Module,
+            type_options: Default::default(),
             fs: Arc::new(crate::fs::MemFs::default()),
         }
     }
@@ -609,6 +792,8 @@ impl std::cmp::PartialEq for Context {
             types_by_ident_by_path: other_tbibp,
             path: other_path,
             base_namespace: other_bn,
+            js_import: other_js_import,
+            type_options: other_type_options,
             fs: other_fs,
         } = other;
 
@@ -616,12 +801,16 @@ impl std::cmp::PartialEq for Context {
             types_by_ident_by_path: tbibp,
             path,
             base_namespace: bn,
+            js_import,
+            type_options,
             fs,
         } = self;
 
         tbibp == other_tbibp
             && path == other_path
             && bn == other_bn
+            && js_import == other_js_import
+            && type_options == other_type_options
             && std::ptr::eq(
                 Arc::as_ptr(fs) as *const dyn Fs as *const u8,
                 Arc::as_ptr(other_fs) as *const dyn Fs as *const u8,
@@ -648,6 +837,8 @@ struct WithContext<T> {
 // doable.
 pub fn target_enrich(
     types_by_ident_by_path: SourceTypesByIdentByPath,
+    js_imports: &JsImports,
+    type_options: TypeOptions,
     fs: Arc<dyn Fs>,
 ) -> WrappedTypesByIdentByPath {
     types_by_ident_by_path.into_iter().fold(
@@ -657,6 +848,7 @@ pub fn target_enrich(
                 .into_iter()
                 .map(|(id, typ)| {
                     let base_namespace = typ.name.to_mod_path_iter(fs.as_ref()).collect();
+                    let js_import = js_imports.js_import_for(&path, &typ.name);
                     (
                         id,
                         WithContext {
@@ -666,6 +858,8 @@ pub fn target_enrich(
                                 path: path.clone(),
                                 fs: fs.clone(),
                                 base_namespace,
+                                js_import,
+                                type_options,
                             },
                         }
                         .into(),
@@ -2,7 +2,7 @@ use crate::fs::Fs;
 use crate::ir::base::{Type as TypeIR, TypeIdent as TypeIdentIR};
 use crate::ir::flattened::{flatten_types, FlatType, TypeIdent as FlatTypeIdent};
 use crate::ir::target_enriched::{
-    target_enrich, TargetEnrichedType, TypeIdent as TargetEnrichedTypeIdent,
+    target_enrich, JsImports, TargetEnrichedType, TypeIdent as TargetEnrichedTypeIdent, TypeOptions,
 };
 use std::cell::RefCell;
 use std::collections::HashMap;
@@ -32,6 +32,11 @@ fn init_to_flat(src: Init) -> Flat {
         .collect()
 }
 
-pub fn to_final_ir(src: Init, fs: Arc<dyn Fs>) -> Rc<RefCell<Final>> {
-    target_enrich(init_to_flat(src), fs)
+pub fn to_final_ir(
+    src: Init,
+    js_imports: &JsImports,
+    type_options: TypeOptions,
+    fs: Arc<dyn Fs>,
+) -> Rc<RefCell<Final>> {
+    target_enrich(init_to_flat(src), js_imports, type_options, fs)
 }
@@ -17,7 +17,8 @@ mod generators;
 
 pub use crate::error::Error;
 pub use crate::fs::{Fs, MemFs, StdFs};
-use crate::ir::to_final_ir;
+pub use crate::ir::BigIntMapping;
+use crate::ir::{to_final_ir, JsImport, JsImports, TypeOptions};
 use codegen::{ModDef, WithFs};
 use parse::{ArcFs, TsTypes};
 use proc_macro2::TokenStream as TokenStream2;
@@ -111,9 +112,131 @@ where
     FS: Fs + Send + Sync + 'static,
     F: FnMut(&Path),
 {
+    generate_rust_for_typescript_with_options(fs, module, Default::default(), process_file)
+}
+
+/// Determines how generated bindings locate the javascript they bind to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsImportMode {
+    /// Import from the js module alongside each typescript definition file, as
+    /// for libraries bundled as es modules.
+    Module,
+    /// Bind to globals, as for UMD libraries loaded via a `<script>` tag.
+    /// Exports of typescript files declaring `export as namespace X` (or
+    /// re-exported from such a file) are bound within the global `X`.
+    Global,
+}
+
+impl Default for JsImportMode {
+    fn default() -> Self {
+        JsImportMode::Module
+    }
+}
+
+/// Options controlling how bindings are generated.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GenerateOptions {
+    /// Whether we import from js modules or bind to globals
+    pub import_mode: JsImportMode,
+    /// The rust type that typescript `bigint`s are represented as
+    pub bigint_mapping: BigIntMapping,
+    /// Whether async iterables are represented as `ts_bindgen_rt::AsyncIterStream`s, which
+    /// requires the `futures` feature of ts-bindgen-rt, rather than `JsValue`s
+    pub async_iter_streams: bool,
+}
+
+/// Like [`generate_rust_for_typescript_with_file_processor`] but with `options` controlling how
+/// the bindings are generated.
+///
+/// ```rust
+/// use ts_bindgen_gen::{
+///     generate_rust_for_typescript_with_options, BigIntMapping, GenerateOptions, JsImportMode,
+///     MemFs,
+/// };
+/// use std::path::Path;
+///
+/// # fn main() -> Result<(), ts_bindgen_gen::Error> {
+/// let fs = {
+///     let mut fs: MemFs = Default::default();
+///     fs.set_cwd(Path::new("/"));
+///     fs.add_file_at(
+///         Path::new("/my-lib.d.ts"),
+///         r#"
+///             export as namespace MyLib;
+///             export declare function factorial(n: bigint): bigint;
+///         "#.to_string(),
+///     );
+///     fs
+/// };
+///
+/// let options = GenerateOptions {
+///     import_mode: JsImportMode::Global,
+///     bigint_mapping: BigIntMapping::I64,
+///     ..Default::default()
+/// };
+/// let rust = generate_rust_for_typescript_with_options(fs, "./my-lib", options, |_| {})?;
+///
+/// assert!(rust.to_string().contains("js_namespace = MyLib"));
+/// assert!(rust.to_string().contains("n : i64"));
+///
+/// # Ok(())
+/// # }
+/// ```
+pub fn generate_rust_for_typescript_with_options<S, FS, F>(
+    fs: FS,
+    module: S,
+    options: GenerateOptions,
+    process_file: F,
+) -> Result<TokenStream2, Error>
+where
+    S: AsRef<str>,
+    FS: Fs + Send + Sync + 'static,
+    F: FnMut(&Path),
+{
+    let GenerateOptions {
+        import_mode,
+        bigint_mapping,
+        async_iter_streams,
+    } = options;
     let arc_fs = Arc::new(fs) as ArcFs;
-    let tbnbf = TsTypes::parse(arc_fs.clone(), module.as_ref())?;
-    let final_ir = to_final_ir(tbnbf, arc_fs.clone());
+    let (tbnbf, js_globals) = TsTypes::parse_with_js_globals(arc_fs.clone(), module.as_ref())?;
+    let js_imports = JsImports {
+        by_file: match import_mode {
+            JsImportMode::Module => Default::default(),
+            // files outside of the input module without their own UMD
+            // namespace are still imported as modules
+            JsImportMode::Global => tbnbf
+                .keys()
+                .filter_map(|file| {
+                    js_globals
+                        .umd_namespaces_by_file
+                        .get(file)
+                        .map(|ns| vec![ns.clone()])
+                        .or_else(|| js_globals.root_files.contains(file).then(Vec::new))
+                        .map(|js_namespace| (file.clone(), JsImport::Global { js_namespace }))
+                })
+                .collect(),
+        },
+        // `declare global` declarations are always global
+        by_name_by_file: js_globals
+            .global_namespaces_by_name_by_file
+            .into_iter()
+            .map(|(file, namespaces_by_name)| {
+                (
+                    file,
+                    namespaces_by_name
+                        .into_iter()
+                        .map(|(name, js_namespace)| (name, JsImport::Global { js_namespace }))
+                        .collect(),
+                )
+            })
+            .collect(),
+    };
+    let type_options = TypeOptions {
+        bigint_mapping,
+        async_iter_streams,
+    };
+    let final_ir = to_final_ir(tbnbf, &js_imports, type_options, arc_fs.clone());
     let final_ir = &*final_ir.borrow();
     let mod_def = ModDef::new(&*arc_fs, final_ir);
     let mod_defs = mod_def
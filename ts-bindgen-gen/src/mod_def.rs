@@ -197,7 +197,7 @@ mod mod_def_tests {
     use crate::error::Error;
     use crate::fs::{MemFs, StdFs};
     use crate::identifier::{make_identifier, to_ident};
-    use crate::ir::{to_final_ir, Builtin, Context, TargetEnrichedTypeInfo};
+    use crate::ir::{to_final_ir, Builtin, Context, JsImport, TargetEnrichedTypeInfo};
     use crate::parse::{ArcFs, TsTypes};
     use std::cell::RefCell;
     use std::fs::{DirBuilder, File};
@@ -217,6 +217,8 @@ mod mod_def_tests {
             path: b_c.clone(),
             fs: fs.clone(),
             base_namespace: b_c.to_mod_path_iter(fs.as_ref()).collect(),
+            js_import: JsImport::Module,
+            type_options: Default::default(),
         };
         File::create(&b_c)?;
 
@@ -289,7 +291,12 @@ mod mod_def_tests {
 
         let arc_fs = Arc::new(fs) as ArcFs;
         let tbnbf = TsTypes::parse(arc_fs.clone(), "/test")?;
-        let ir = to_final_ir(tbnbf, arc_fs.clone());
+        let ir = to_final_ir(
+            tbnbf,
+            &Default::default(),
+            Default::default(),
+            arc_fs.clone(),
+        );
         let mods = ModDef::new(&*arc_fs, &*ir.borrow());
 
         assert_eq!(mods.children.len(), 1);
@@ -315,7 +322,12 @@ mod mod_def_tests {
 
         let arc_fs = Arc::new(fs) as ArcFs;
         let tbnbf = TsTypes::parse(arc_fs.clone(), "/abc/def/test")?;
-        let ir = to_final_ir(tbnbf, arc_fs.clone());
+        let ir = to_final_ir(
+            tbnbf,
+            &Default::default(),
+            Default::default(),
+            arc_fs.clone(),
+        );
         let mods = ModDef::new(&*arc_fs, &*ir.borrow());
 
         assert_eq!(mods.children.len(), 1);
@@ -344,7 +356,12 @@ mod mod_def_tests {
 
         let arc_fs = Arc::new(fs) as ArcFs;
         let tbnbf = TsTypes::parse(arc_fs.clone(), "/abc/def/test")?;
-        let ir = to_final_ir(tbnbf, arc_fs.clone());
+        let ir = to_final_ir(
+            tbnbf,
+            &Default::default(),
+            Default::default(),
+            arc_fs.clone(),
+        );
         let mods = ModDef::new(&*arc_fs, &*ir.borrow());
 
         assert_eq!(mods.children.len(), 1);
@@ -1,28 +1,56 @@
 use crate::error::{Error, InternalError};
 use crate::fs::Fs;
 use crate::ir::base::{
-    Alias, BaseClass, Class, Ctor, CtorGroup, Enum, EnumMember, EnumValue, Func, FuncGroup,
-    Indexer, Interface, Intersection, JsSysBuiltin, LitBoolean, LitNumber, LitString, Member,
-    NamespaceImport, Param, PrimitiveAny, PrimitiveBigInt, PrimitiveBoolean, PrimitiveNull,
-    PrimitiveNumber, PrimitiveObject, PrimitiveString, PrimitiveUndefined, PrimitiveVoid, Tuple,
-    Type, TypeIdent, TypeInfo, TypeName, TypeParamConfig, TypeQuery, TypeRef, Union,
+    Alias, BaseClass, Class, Conditional, Ctor, CtorGroup, Enum, EnumMember, EnumValue, Func,
+    FuncGroup, IndexedAccess, Indexer, Interface, Intersection, JsSysBuiltin, LitBigInt,
+    LitBoolean, LitNumber, LitString, MappedModifier, MappedType, Member, MemberKey,
+    NamespaceImport, Param, PolymorphicThis, PrimitiveAny, PrimitiveBigInt, PrimitiveBoolean,
+    PrimitiveNull, PrimitiveNumber, PrimitiveObject, PrimitiveString, PrimitiveUndefined,
+    PrimitiveVoid, Property, TemplateLiteral, Tuple, Type, TypeIdent, TypeInfo, TypeName,
+    TypeParamConfig, TypePredicate, TypeQuery, TypeRef, Union,
 };
 use crate::module_resolution::{get_ts_path, typings_module_resolver};
-use std::collections::{hash_map::Entry, HashMap};
+use std::collections::{hash_map::Entry, HashMap, HashSet};
 use std::convert::{TryFrom, TryInto};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::{io, io::Read};
-use swc_common::{sync::Lrc, FileLoader, FilePathMapping, SourceMap, Spanned};
+use swc_common::{
+    comments::{CommentKind, SingleThreadedComments},
+    sync::Lrc,
+    BytePos, FileLoader, FilePathMapping, SourceMap, Spanned,
+};
 use swc_ecma_ast::*;
 use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax, TsConfig};
 
 pub type ArcFs = Arc<dyn Fs + Send + Sync>;
 
+/// Describes types whose javascript lives on the global object rather than in
+/// the js module for the file that declares them
+#[derive(Debug, Clone, Default)]
+pub struct JsGlobals {
+    /// Global names declared via `export as namespace X` for UMD modules
+    pub umd_namespaces_by_file: HashMap<PathBuf, String>,
+    /// Js namespace within the global object of types declared in
+    /// `declare global` blocks, by file and type name parts
+    pub global_namespaces_by_name_by_file: HashMap<PathBuf, HashMap<Vec<String>, Vec<String>>>,
+    /// The input module and the files it (transitively) re-exports, whose
+    /// globals live under the input module's UMD namespace
+    pub root_files: HashSet<PathBuf>,
+}
+
 pub struct TsTypes {
     errors: Vec<InternalError>,
     types_by_name_by_file: HashMap<PathBuf, HashMap<TypeIdent, Type>>,
+    js_globals: JsGlobals,
     namespace_stack: Vec<Vec<String>>,
+    /// Length of the namespace at which we entered a `declare global` block,
+    /// if we are within one
+    global_namespace_depth: Option<usize>,
+    /// Module that the declarations we are processing augment, if any
+    augmented_module: Option<PathBuf>,
+    /// Files that assign their exports via `export =`
+    export_assignment_files: HashSet<PathBuf>,
     fs: ArcFs,
     source_map: Lrc<SourceMap>,
 }
@@ -253,17 +281,75 @@ impl<T: ExprKeyed> KeyedExt for T {
                 }
             },
             Expr::Ident(Ident { sym, .. }) => Some(sym.to_string()),
-            _ => {
+            _ => well_known_symbol_key(expr).or_else(|| {
                 println!(
                     "We only handle literal and identifier properties. Received {:?}",
                     expr
                 );
                 None
-            }
+            }),
         }
     }
 }
 
+/// The key of `[Symbol.iterator]` members
+const ITERATOR_KEY: &str = "[Symbol.iterator]";
+
+/// The key of `[Symbol.asyncIterator]` members
+const ASYNC_ITERATOR_KEY: &str = "[Symbol.asyncIterator]";
+
+/// Returns a key of the form `[Symbol.name]` for members keyed by well-known
+/// symbols, like `[Symbol.iterator]`.
+fn well_known_symbol_key(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Member(MemberExpr {
+            obj: ExprOrSuper::Expr(obj),
+            prop,
+            computed: false,
+            ..
+        }) => match (&**obj, &**prop) {
+            (Expr::Ident(Ident { sym: obj, .. }), Expr::Ident(Ident { sym: prop, .. }))
+                if &**obj == "Symbol" =>
+            {
+                Some(format!("[Symbol.{}]", prop))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn is_well_known_symbol_key(key: &str) -> bool {
+    key.starts_with("[Symbol.")
+}
+
+/// Returns the type of the items produced by `iterator_type`, which should be
+/// the return type of a `[Symbol.iterator]` or `[Symbol.asyncIterator]` method
+/// and named one of `iterator_names`.
+fn iterated_type(iterator_type: TypeInfo, iterator_names: &[&str]) -> TypeInfo {
+    let item_type = match iterator_type {
+        TypeInfo::Ref(TypeRef {
+            referent,
+            type_params,
+        }) => {
+            let name = match &referent.name {
+                TypeIdent::Name(n) => Some(n),
+                TypeIdent::QualifiedName(names) => names.last(),
+                _ => None,
+            };
+            name.filter(|n| iterator_names.contains(&n.as_str()))
+                .and_then(|_| type_params.into_iter().next())
+        }
+        _ => None,
+    };
+
+    item_type.unwrap_or(TypeInfo::PrimitiveAny(PrimitiveAny()))
+}
+
+const ITERATOR_NAMES: &[&str] = &["Iterator", "IterableIterator", "Generator"];
+
+const ASYNC_ITERATOR_NAMES: &[&str] = &["AsyncIterator", "AsyncIterableIterator", "AsyncGenerator"];
+
 impl ExprKeyed for TsPropertySignature {
     fn expr_key(&self) -> &Expr {
         &*self.key
@@ -300,6 +386,7 @@ impl KeyedExt for PropName {
             PropName::Ident(ident) => Some(ident.sym.to_string()),
             PropName::Str(s) => Some(s.value.to_string()),
             PropName::Num(n) => Some(n.value.to_string()),
+            PropName::Computed(ComputedPropName { expr, .. }) => well_known_symbol_key(expr),
             _ => None,
         }
     }
@@ -503,6 +590,7 @@ impl FnParamExt for ArrayPat {
                 .unwrap_or_else(|| {
                     Ok(TypeInfo::Array {
                         item_type: Box::new(TypeInfo::PrimitiveAny(PrimitiveAny())),
+                        readonly: false,
                     })
                 })?,
         })
@@ -545,7 +633,7 @@ trait FuncExt {
     fn to_func(&self, ts_path: &Path, ts_types: &mut TsTypes) -> Result<Func, InternalError> {
         Ok(Func {
             params: self.params(ts_path, ts_types)?,
-            type_params: ts_types.process_fn_type_params(ts_path, self.type_params()),
+            type_params: ts_types.process_fn_type_params(ts_path, self.type_params())?,
             return_type: Box::new(
                 self.return_type()
                     .map(|t| ts_types.process_type(ts_path, t))
@@ -595,6 +683,24 @@ impl FuncExt for TsMethodSignature {
     }
 }
 
+impl FuncExt for TsCallSignatureDecl {
+    fn params(&self, ts_path: &Path, ts_types: &mut TsTypes) -> Result<Vec<Param>, InternalError> {
+        self.params
+            .iter()
+            .enumerate()
+            .map(|(i, param)| param.to_param(ts_path, i, ts_types))
+            .collect()
+    }
+
+    fn type_params(&self) -> &Option<TsTypeParamDecl> {
+        &self.type_params
+    }
+
+    fn return_type(&self) -> Option<&TsType> {
+        self.type_ann.as_ref().map(|t| &*t.type_ann)
+    }
+}
+
 impl FuncExt for TsFnType {
     fn params(&self, ts_path: &Path, ts_types: &mut TsTypes) -> Result<Vec<Param>, InternalError> {
         self.params
@@ -678,27 +784,43 @@ impl IndexerExt for TsIndexSignature {
 }
 
 trait HasTypeParams {
-    fn type_param_config(&self) -> Vec<(String, TypeParamConfig)>;
+    fn type_param_config(
+        &self,
+        ts_path: &Path,
+        ts_types: &mut TsTypes,
+    ) -> Result<Vec<(String, TypeParamConfig)>, InternalError>;
 }
 
 impl HasTypeParams for Option<TsTypeParamDecl> {
-    fn type_param_config(&self) -> Vec<(String, TypeParamConfig)> {
+    fn type_param_config(
+        &self,
+        ts_path: &Path,
+        ts_types: &mut TsTypes,
+    ) -> Result<Vec<(String, TypeParamConfig)>, InternalError> {
         self.as_ref()
             .map(|tps| {
                 tps.params
                     .iter()
                     .map(|p| {
-                        (
+                        Ok((
                             p.name.sym.to_string(),
                             TypeParamConfig {
-                                constraint: None,
-                                default_type_arg: None,
+                                constraint: p
+                                    .constraint
+                                    .as_ref()
+                                    .map(|c| ts_types.process_type(ts_path, c))
+                                    .transpose()?,
+                                default_type_arg: p
+                                    .default
+                                    .as_ref()
+                                    .map(|d| ts_types.process_type(ts_path, d))
+                                    .transpose()?,
                             },
-                        )
+                        ))
                     })
                     .collect()
             })
-            .unwrap_or_default()
+            .unwrap_or_else(|| Ok(Default::default()))
     }
 }
 
@@ -728,6 +850,130 @@ impl FileLoader for FsFileLoader {
     }
 }
 
+/// Merges `new_type` into `cur` following typescript's declaration merging rules.
+/// Function declarations become overloads, interfaces are combined with each other, and
+/// interfaces merged with a class contribute their members to the class.
+/// Anything else is replaced by the newer declaration.
+fn merge_declarations(cur: &mut Type, new_type: Type) {
+    let Type {
+        name,
+        is_exported,
+        info: new_info,
+    } = new_type;
+    let cur_info = std::mem::replace(&mut cur.info, TypeInfo::PrimitiveAny(PrimitiveAny()));
+
+    cur.info = match (cur_info, new_info) {
+        (TypeInfo::FuncGroup(fg), TypeInfo::FuncGroup(new_fg)) => {
+            TypeInfo::FuncGroup(merge_func_groups(fg, new_fg))
+        }
+        (TypeInfo::Interface(iface), TypeInfo::Interface(new_iface)) => {
+            TypeInfo::Interface(merge_interfaces(iface, new_iface))
+        }
+        (TypeInfo::Class(class), TypeInfo::Interface(iface))
+        | (TypeInfo::Interface(iface), TypeInfo::Class(class)) => {
+            TypeInfo::Class(merge_interface_into_class(class, iface, &cur.name))
+        }
+        (_, new_info) => {
+            cur.name = name;
+            cur.is_exported = is_exported;
+            new_info
+        }
+    };
+    cur.is_exported = cur.is_exported || is_exported;
+}
+
+fn merge_func_groups(mut fg: FuncGroup, other: FuncGroup) -> FuncGroup {
+    fg.overloads.extend(other.overloads);
+    fg
+}
+
+fn merge_interfaces(mut iface: Interface, other: Interface) -> Interface {
+    for (name, typ) in other.fields {
+        let typ = match (iface.fields.remove(&name), typ) {
+            (Some(TypeInfo::FuncGroup(fg)), TypeInfo::FuncGroup(new_fg)) => {
+                TypeInfo::FuncGroup(merge_func_groups(fg, new_fg))
+            }
+            (_, typ) => typ,
+        };
+        iface.fields.insert(name, typ);
+    }
+    iface.readonly_fields.extend(other.readonly_fields);
+
+    for base in other.extends {
+        if !iface.extends.contains(&base) {
+            iface.extends.push(base);
+        }
+    }
+
+    if iface.type_params.is_empty() {
+        iface.type_params = other.type_params;
+    }
+
+    iface.indexer = iface.indexer.or(other.indexer);
+    iface.constructor = iface.constructor.or(other.constructor);
+    iface.callable = match (iface.callable, other.callable) {
+        (Some(fg), Some(new_fg)) => Some(merge_func_groups(fg, new_fg)),
+        (fg, new_fg) => fg.or(new_fg),
+    };
+    iface.iterator = iface.iterator.or(other.iterator);
+    iface.async_iterator = iface.async_iterator.or(other.async_iterator);
+
+    iface
+}
+
+fn merge_interface_into_class(mut class: Class, iface: Interface, class_name: &TypeName) -> Class {
+    for (name, typ) in iface.fields {
+        let key = MemberKey::Instance(name.clone());
+        if class.members.contains_key(&key) {
+            continue;
+        }
+
+        let member = match typ {
+            TypeInfo::FuncGroup(FuncGroup { overloads }) => Member::Method(FuncGroup {
+                overloads: overloads
+                    .into_iter()
+                    .map(|f| Func {
+                        class_name: Some(class_name.clone()),
+                        ..f
+                    })
+                    .collect(),
+            }),
+            type_info => Member::Property(Property {
+                readonly: iface.readonly_fields.contains(&name),
+                type_info,
+            }),
+        };
+        class.members.insert(key, member);
+    }
+
+    if let Some(item_type) = iface.iterator {
+        class
+            .members
+            .entry(MemberKey::Instance(ITERATOR_KEY.to_string()))
+            .or_insert(Member::Iterator(*item_type));
+    }
+    if let Some(item_type) = iface.async_iterator {
+        class
+            .members
+            .entry(MemberKey::Instance(ASYNC_ITERATOR_KEY.to_string()))
+            .or_insert(Member::AsyncIterator(*item_type));
+    }
+
+    for base in iface.extends {
+        if let BaseClass::Unresolved(base) = base {
+            if !class.implements.contains(&base) {
+                class.implements.push(base);
+            }
+        }
+    }
+
+    if class.type_params.is_empty() {
+        class.type_params = iface.type_params;
+    }
+
+    class
+}
+
 impl TsTypes {
     /// Given a filesystem, `fs`, and a `module_name` pointing into that filesystem,
     /// return a map from files to a map from type names to types.
@@ -735,6 +981,18 @@ impl TsTypes {
         fs: ArcFs,
         module_name: &str,
     ) -> Result<HashMap<PathBuf, HashMap<TypeIdent, Type>>, Error> {
+        Self::parse_with_js_globals(fs, module_name).map(|(types, _)| types)
+    }
+
+    /// Like [`TsTypes::parse`] but also returns the [`JsGlobals`] describing
+    /// which types live on the global object. Files re-exported from
+    /// `module_name` without their own `export as namespace` are available
+    /// under the UMD namespace of `module_name`.
+    #[allow(clippy::type_complexity)]
+    pub fn parse_with_js_globals(
+        fs: ArcFs,
+        module_name: &str,
+    ) -> Result<(HashMap<PathBuf, HashMap<TypeIdent, Type>>, JsGlobals), Error> {
         let file_loader =
             Box::new(FsFileLoader::new(Arc::clone(&fs))) as Box<dyn FileLoader + Send + Sync>;
         let source_map: Lrc<SourceMap> = Lrc::new(SourceMap::with_file_loader(
@@ -743,15 +1001,26 @@ impl TsTypes {
         ));
         let mut tt = TsTypes {
             types_by_name_by_file: Default::default(),
+            js_globals: Default::default(),
             namespace_stack: Default::default(),
+            global_namespace_depth: None,
+            augmented_module: None,
+            export_assignment_files: Default::default(),
             errors: Default::default(),
             fs: Arc::clone(&fs),
             source_map,
         };
 
-        if let Err(err) = tt.process_module(None, module_name) {
-            tt.record_error(err);
-        }
+        let (root_namespace, root_files) = match tt.process_module(None, module_name) {
+            Ok(root) => (
+                tt.js_globals.umd_namespaces_by_file.get(&root).cloned(),
+                tt.files_reexported_from(root),
+            ),
+            Err(err) => {
+                tt.record_error(err);
+                (None, Default::default())
+            }
+        };
 
         let mut resolved_types_by_name_by_file: HashMap<PathBuf, HashMap<TypeIdent, Type>> =
             HashMap::new();
@@ -765,7 +1034,69 @@ impl TsTypes {
 
         tt.types_by_name_by_file = resolved_types_by_name_by_file;
 
+        let umd_namespaces_by_file = tt
+            .types_by_name_by_file
+            .keys()
+            .filter_map(|file| {
+                tt.js_globals
+                    .umd_namespaces_by_file
+                    .get(file)
+                    .or_else(|| {
+                        root_namespace
+                            .as_ref()
+                            .filter(|_| root_files.contains(file))
+                    })
+                    .map(|ns| (file.clone(), ns.clone()))
+            })
+            .collect();
+        let js_globals = JsGlobals {
+            umd_namespaces_by_file,
+            global_namespaces_by_name_by_file: std::mem::take(
+                &mut tt.js_globals.global_namespaces_by_name_by_file,
+            ),
+            root_files,
+        };
+
         tt.try_into_types_by_name_by_file()
+            .map(|types| (types, js_globals))
+    }
+
+    /// Returns `root` and all files whose exports `root` re-exports, directly
+    /// or transitively
+    fn files_reexported_from(&self, root: PathBuf) -> HashSet<PathBuf> {
+        let mut files: HashSet<PathBuf> = Default::default();
+        let mut to_visit = vec![root];
+
+        while let Some(file) = to_visit.pop() {
+            if !files.insert(file.clone()) {
+                continue;
+            }
+
+            let exported_types = self
+                .types_by_name_by_file
+                .get(&file)
+                .into_iter()
+                .flat_map(|types_by_name| types_by_name.values())
+                .filter(|t| t.is_exported);
+            for typ in exported_types {
+                // export * from copies types from their declaring file
+                if typ.name.file != file {
+                    to_visit.push(typ.name.file.clone());
+                }
+
+                if let TypeInfo::NamespaceImport(
+                    NamespaceImport::Default { src }
+                    | NamespaceImport::All { src }
+                    | NamespaceImport::Named { src, .. }
+                    | NamespaceImport::Namespace { src, .. },
+                ) = &typ.info
+                {
+                    to_visit.push(src.clone());
+                }
+            }
+        }
+
+        files
     }
 
     pub fn try_into_types_by_name_by_file(
@@ -778,8 +1109,12 @@ impl TsTypes {
         }
     }
 
-    fn load_module(&mut self, ts_path: &Path) -> Result<Module, InternalError> {
+    fn load_module(
+        &mut self,
+        ts_path: &Path,
+    ) -> Result<(Module, Vec<ReferenceDirective>), InternalError> {
         let fm = self.source_map.load_file(ts_path)?;
+        let comments = SingleThreadedComments::default();
         let lexer = Lexer::new(
             Syntax::Typescript(TsConfig {
                 tsx: true,
@@ -791,13 +1126,132 @@ impl TsTypes {
             }),
             Default::default(),
             StringInput::from(&*fm),
-            None,
+            Some(&comments),
         );
 
         let mut parser = Parser::new_from(lexer);
         let module = parser.parse_typescript_module()?;
 
-        Ok(module)
+        // reference directives are only honored in the comments preceding the first statement
+        let directives_end = module
+            .body
+            .first()
+            .map_or(BytePos(u32::MAX), |item| item.span().lo);
+        let (leading_comments, _) = comments.borrow_all();
+        let mut leading_comments: Vec<_> = leading_comments
+            .iter()
+            .filter(|(pos, _)| **pos <= directives_end)
+            .flat_map(|(_, cmts)| cmts.iter().cloned())
+            .collect();
+        leading_comments.sort_by_key(|cmt| cmt.span.lo);
+        let references = leading_comments
+            .iter()
+            .filter(|cmt| cmt.kind == CommentKind::Line)
+            .filter_map(|cmt| ReferenceDirective::parse(&cmt.text))
+            .collect();
+
+        Ok((module, references))
+    }
+
+    fn process_reference_directives(&mut self, ts_path: &Path, references: &[ReferenceDirective]) {
+        let base = match path_parent(ts_path) {
+            Ok(base) => base.to_path_buf(),
+            Err(err) => {
+                self.record_error(err);
+                return;
+            }
+        };
+
+        for reference in references {
+            let file_result = match reference {
+                ReferenceDirective::Path(path) => {
+                    let import =
+                        if path.starts_with('.') || self.fs.is_absolute_path(Path::new(path)) {
+                            path.clone()
+                        } else {
+                            format!("./{}", path)
+                        };
+                    self.process_module(Some(base.clone()), &import)
+                }
+                ReferenceDirective::Types(types) => self
+                    .process_module(Some(base.clone()), &format!("@types/{}", types))
+                    .or_else(|_| self.process_module(Some(base.clone()), types)),
+            };
+
+            let file = match file_result {
+                Ok(file) => file,
+                Err(err) => {
+                    self.record_error(err);
+                    continue;
+                }
+            };
+
+            // referenced files contribute their declarations to our type environment. local
+            // declarations processed later will shadow these. namespaced declarations (e.g.
+            // NodeJS.Timeout) are made available by importing their top-level namespace.
+            let (names, namespaces): (HashSet<String>, HashSet<String>) = self
+                .types_by_name_by_file
+                .get(&file)
+                .map(|types| {
+                    types
+                        .keys()
+                        .fold(Default::default(), |(mut names, mut namespaces), name| {
+                            match name {
+                                TypeIdent::Name(name) => {
+                                    names.insert(name.clone());
+                                }
+                                TypeIdent::QualifiedName(name_parts) if name_parts.len() > 1 => {
+                                    namespaces.insert(name_parts[0].clone());
+                                }
+                                _ => (),
+                            }
+                            (names, namespaces)
+                        })
+                })
+                .unwrap_or_default();
+            let local_types = self
+                .types_by_name_by_file
+                .entry(ts_path.to_path_buf())
+                .or_default();
+
+            let imports = names
+                .iter()
+                .map(|name| {
+                    (
+                        name.clone(),
+                        NamespaceImport::Named {
+                            src: file.clone(),
+                            name: name.clone(),
+                        },
+                    )
+                })
+                .chain(namespaces.difference(&names).map(|ns| {
+                    (
+                        ns.clone(),
+                        NamespaceImport::Namespace {
+                            src: file.clone(),
+                            name_parts: vec![ns.clone()],
+                        },
+                    )
+                }));
+
+            for (name, import) in imports {
+                let ident = TypeIdent::Name(name);
+                if !local_types.contains_key(&ident) {
+                    local_types.insert(
+                        ident.clone(),
+                        Type {
+                            name: TypeName {
+                                file: ts_path.to_path_buf(),
+                                name: ident,
+                            },
+                            is_exported: false,
+                            info: TypeInfo::NamespaceImport(import),
+                        },
+                    );
+                }
+            }
+        }
     }
 
     fn make_type_ref(&mut self, referent: TypeName, type_params: Vec<TypeInfo>) -> TypeRef {
@@ -829,7 +1283,9 @@ impl TsTypes {
         module_name: &str,
     ) -> Result<PathBuf, InternalError> {
         self.process_module_with_items(module_base, module_name, false, |t, p| {
-            t.load_module(p).map(|m| m.body)
+            let (module, references) = t.load_module(p)?;
+            t.process_reference_directives(p, &references);
+            Ok(module.body)
         })
     }
 
@@ -910,6 +1366,21 @@ impl TsTypes {
         match typ {
             Err(e) => self.record_error(e),
             Ok(new_type) => {
+                if let Some((src, name)) = self.augmented_reexport(file, &name, &new_type) {
+                    // augmentations of a re-exported declaration merge into the
+                    // original declaration
+                    let name = TypeIdent::Name(name);
+                    let new_type = Type {
+                        name: TypeName {
+                            file: src.clone(),
+                            name: name.clone(),
+                        },
+                        ..new_type
+                    };
+                    self.set_type_for_name_for_file(&src, name, Ok(new_type));
+                    return;
+                }
+
                 self.types_by_name_by_file
                     .entry(file.to_path_buf())
                     .and_modify(
@@ -917,20 +1388,7 @@ impl TsTypes {
                             .entry(name)
                         {
                             Entry::Occupied(mut current_entry) => {
-                                let cur = current_entry.get_mut();
-                                match (&mut cur.info, &new_type.info) {
-                                    (
-                                        TypeInfo::FuncGroup(ref mut fg),
-                                        TypeInfo::FuncGroup(FuncGroup {
-                                            overloads: new_overloads,
-                                        }),
-                                    ) => {
-                                        fg.overloads.extend(new_overloads.iter().cloned());
-                                    }
-                                    _ => {
-                                        *cur = new_type;
-                                    }
-                                }
+                                merge_declarations(current_entry.get_mut(), new_type);
                             }
                             Entry::Vacant(current_entry) => {
                                 current_entry.insert(new_type);
@@ -941,6 +1399,48 @@ impl TsTypes {
         }
     }
 
+    /// If we are augmenting `file` and `name` is a re-export within it, returns
+    /// the file and name of the re-exported declaration that `new_type` should
+    /// be merged into.
+    fn augmented_reexport(
+        &self,
+        file: &Path,
+        name: &TypeIdent,
+        new_type: &Type,
+    ) -> Option<(PathBuf, String)> {
+        if self.augmented_module.as_deref() != Some(file)
+            || matches!(new_type.info, TypeInfo::NamespaceImport(_))
+        {
+            return None;
+        }
+
+        let reexport = |file: &Path, name: &TypeIdent| match &self
+            .types_by_name_by_file
+            .get(file)?
+            .get(name)?
+            .info
+        {
+            TypeInfo::NamespaceImport(NamespaceImport::Named { src, name })
+                if src.as_path() != file =>
+            {
+                Some((src.clone(), name.clone()))
+            }
+            _ => None,
+        };
+
+        // follow chains of re-exports to the original declaration
+        let mut visited = HashSet::new();
+        let mut target = reexport(file, name)?;
+        while visited.insert(target.clone()) {
+            match reexport(&target.0, &TypeIdent::Name(target.1.clone())) {
+                Some(next) => target = next,
+                None => break,
+            }
+        }
+
+        Some(target)
+    }
+
     fn export_type_with_name(
         &mut self,
         ts_path: &Path,
@@ -1052,6 +1552,58 @@ impl TsTypes {
         })
     }
 
+    fn process_import_equals(&mut self, ts_path: &Path, decl: &TsImportEqualsDecl) {
+        let info = match &decl.module_ref {
+            TsModuleRef::TsExternalModuleRef(TsExternalModuleRef { expr, .. }) => {
+                // import x = require('...') binds x to the module's `export =`,
+                // which we record as the module's default export
+                let base = match path_parent(ts_path) {
+                    Ok(base) => base,
+                    Err(err) => {
+                        self.record_error(err);
+                        return;
+                    }
+                };
+                let file_result =
+                    self.process_module(Some(base.to_path_buf()), &expr.value.to_string());
+                match file_result {
+                    Ok(file) => TypeInfo::NamespaceImport(NamespaceImport::Default { src: file }),
+                    Err(err) => {
+                        self.record_error(err);
+                        return;
+                    }
+                }
+            }
+            TsModuleRef::TsEntityName(entity_name) => {
+                // import X = A.B.C aliases a name from this file. aliases of
+                // namespaces are bound to the namespace when resolving names
+                let type_name = match entity_name {
+                    TsEntityName::Ident(Ident { sym, .. }) => {
+                        TypeName::for_name(ts_path, &sym.to_string())
+                    }
+                    TsEntityName::TsQualifiedName(qn) => {
+                        self.qualified_name_to_type_name(ts_path, qn)
+                    }
+                };
+                TypeInfo::Alias(Alias {
+                    target: Box::new(TypeInfo::Ref(
+                        self.make_type_ref(type_name, Default::default()),
+                    )),
+                    type_params: Default::default(),
+                })
+            }
+        };
+
+        self.set_type_for_file(
+            ts_path,
+            Ok(Type {
+                name: self.ns_type_name(TypeName::for_name(ts_path, &decl.id.sym.to_string())),
+                is_exported: decl.is_export,
+                info,
+            }),
+        );
+    }
+
     fn process_export_all(&mut self, ts_path: &Path, export_all: &ExportAll) {
         let s = export_all.src.value.to_string();
         let dir = match path_parent(ts_path) {
@@ -1161,10 +1713,10 @@ impl TsTypes {
                 TypeInfo::PrimitiveUndefined(PrimitiveUndefined())
             }
             TsKeywordTypeKind::TsIntrinsicKeyword => {
-                return Err(InternalError::with_msg_and_span(
-                    "intrinsic keyword not supported",
-                    keyword.span(),
-                ))
+                // only the string manipulation types (Uppercase, etc.) are
+                // intrinsic. we evaluate uses of them during name resolution
+                // so their declarations only need to be strings
+                TypeInfo::PrimitiveString(PrimitiveString())
             }
         })
     }
@@ -1176,6 +1728,7 @@ impl TsTypes {
     ) -> Result<TypeInfo, InternalError> {
         Ok(TypeInfo::Array {
             item_type: Box::new(self.process_type(ts_path, elem_type)?),
+            readonly: false,
         })
     }
 
@@ -1199,17 +1752,6 @@ impl TsTypes {
             .map(|t| self.process_type(ts_path, t))
             .collect::<Result<Vec<_>, InternalError>>()?;
 
-        // Special case: unions of string literals get turned into a single String. This is valid while
-        //               we don't support serializing string literals into type-safe enums, but instead just turn
-        //               them into `String`.
-        if union_items
-            .iter()
-            .all(|t| matches!(t, TypeInfo::LitString(_)))
-        {
-            return Ok(TypeInfo::PrimitiveString(PrimitiveString()));
-        }
-
-        // Normal union
         Ok(TypeInfo::Union(Union { types: union_items }))
     }
 
@@ -1260,18 +1802,40 @@ impl TsTypes {
             };
         }
 
+        let callable = self.process_interface_callable(ts_path, members)?;
+        if let Some(callable) = callable.as_ref() {
+            if members.len() == callable.overloads.len() {
+                // a type literal with only call signatures is just a function type
+                return Ok(TypeInfo::FuncGroup(callable.clone()));
+            }
+        }
+
         Ok(TypeInfo::Interface(Interface {
             indexer: self.process_interface_indexer(ts_path, members)?,
             extends: Default::default(),
             fields: self.process_interface_members(ts_path, members)?,
+            readonly_fields: self.process_interface_readonly_fields(members),
             type_params: Default::default(),
             constructor: self.process_interface_constructor(ts_path, members)?,
+            callable,
+            iterator: self.process_interface_iterator(
+                ts_path,
+                members,
+                ITERATOR_KEY,
+                ITERATOR_NAMES,
+            )?,
+            async_iterator: self.process_interface_iterator(
+                ts_path,
+                members,
+                ASYNC_ITERATOR_KEY,
+                ASYNC_ITERATOR_NAMES,
+            )?,
         }))
     }
 
     fn process_literal_type(
         &mut self,
-        _ts_path: &Path,
+        ts_path: &Path,
         lit: &TsLitType,
     ) -> Result<TypeInfo, InternalError> {
         Ok(match &lit.lit {
@@ -1280,13 +1844,21 @@ impl TsTypes {
                 s: s.value.to_string(),
             }),
             TsLit::Bool(b) => TypeInfo::LitBoolean(LitBoolean { b: b.value }),
-            TsLit::BigInt(_) => {
-                return Err(InternalError::with_msg_and_span(
-                    "we don't support literal bigints yet",
-                    lit.span(),
-                ))
-            }
-            TsLit::Tpl(_) => TypeInfo::LitString(LitString { s: "".to_string() }),
+            TsLit::BigInt(b) => TypeInfo::LitBigInt(LitBigInt {
+                n: b.value.to_string(),
+            }),
+            TsLit::Tpl(tpl) => TypeInfo::TemplateLiteral(TemplateLiteral {
+                quasis: tpl
+                    .quasis
+                    .iter()
+                    .map(|q| q.cooked.as_ref().unwrap_or(&q.raw).value.to_string())
+                    .collect(),
+                types: tpl
+                    .types
+                    .iter()
+                    .map(|t| self.process_type(ts_path, t))
+                    .collect::<Result<_, _>>()?,
+            }),
         })
     }
 
@@ -1304,10 +1876,10 @@ impl TsTypes {
 
     fn process_fn_type_params(
         &mut self,
-        _ts_path: &Path,
+        ts_path: &Path,
         type_params: &Option<TsTypeParamDecl>,
-    ) -> Vec<(String, TypeParamConfig)> {
-        type_params.type_param_config()
+    ) -> Result<Vec<(String, TypeParamConfig)>, InternalError> {
+        type_params.type_param_config(ts_path, self)
     }
 
     fn process_fn_type(
@@ -1322,7 +1894,7 @@ impl TsTypes {
     ) -> Result<TypeInfo, InternalError> {
         Ok(TypeInfo::FuncGroup(FuncGroup {
             overloads: vec![Func {
-                type_params: self.process_fn_type_params(ts_path, type_params),
+                type_params: self.process_fn_type_params(ts_path, type_params)?,
                 params: self.process_params(ts_path, params)?,
                 return_type: Box::new(self.process_type(ts_path, &type_ann.type_ann)?),
                 class_name: None,
@@ -1348,28 +1920,29 @@ impl TsTypes {
         &mut self,
         ts_path: &Path,
         TsTypePredicate {
+            asserts,
             param_name,
             type_ann,
             ..
         }: &TsTypePredicate,
     ) -> Result<TypeInfo, InternalError> {
-        Ok(TypeInfo::FuncGroup(FuncGroup {
-            overloads: vec![Func {
-                type_params: Default::default(),
-                params: vec![Param {
-                    name: match param_name {
-                        TsThisTypeOrIdent::Ident(ident) => ident.sym.to_string(),
-                        TsThisTypeOrIdent::TsThisType(_) => "this".to_string(),
-                    },
-                    is_variadic: false,
-                    type_info: type_ann
-                        .as_ref()
-                        .map(|t| self.process_type(ts_path, &t.type_ann))
-                        .unwrap_or(Ok(TypeInfo::PrimitiveAny(PrimitiveAny())))?,
-                }],
-                return_type: Box::new(TypeInfo::PrimitiveBoolean(PrimitiveBoolean())),
-                class_name: None,
-            }],
+        // assertion functions throw instead of returning false so they
+        // return nothing of interest
+        if *asserts {
+            return Ok(TypeInfo::PrimitiveVoid(PrimitiveVoid()));
+        }
+
+        let asserted_type = match type_ann {
+            Some(type_ann) => self.process_type(ts_path, &type_ann.type_ann)?,
+            None => return Ok(TypeInfo::PrimitiveBoolean(PrimitiveBoolean())),
+        };
+
+        Ok(TypeInfo::TypePredicate(TypePredicate {
+            param_name: match param_name {
+                TsThisTypeOrIdent::Ident(ident) => ident.sym.to_string(),
+                TsThisTypeOrIdent::TsThisType(_) => "this".to_string(),
+            },
+            asserted_type: Box::new(asserted_type),
         }))
     }
 
@@ -1378,24 +1951,107 @@ impl TsTypes {
         ts_path: &Path,
         TsTupleType { elem_types, .. }: &TsTupleType,
     ) -> Result<TypeInfo, InternalError> {
-        Ok(TypeInfo::Tuple(Tuple {
-            types: elem_types
-                .iter()
-                .map(|t| self.process_type(ts_path, &t.ty))
-                .collect::<Result<Vec<_>, InternalError>>()?,
-        }))
-    }
+        let mut types = Vec::with_capacity(elem_types.len());
+        let mut labels = Vec::with_capacity(elem_types.len());
+        let mut rest_indices = Vec::new();
+
+        for TsTupleElement { label, ty, .. } in elem_types {
+            // labeled elements carry their optional and rest markers on the label
+            // (`b?: number`, `...c: number[]`) while unlabeled elements carry them
+            // on the type (`number?`, `...number[]`)
+            let (label, is_optional, is_rest) = match label {
+                Some(Pat::Ident(BindingIdent { id, .. })) => {
+                    (Some(id.sym.to_string()), id.optional, false)
+                }
+                Some(Pat::Rest(RestPat { arg, .. })) => match &**arg {
+                    Pat::Ident(BindingIdent { id, .. }) => (Some(id.sym.to_string()), false, true),
+                    _ => (None, false, true),
+                },
+                _ => (None, false, false),
+            };
 
-    fn process_type_op(
+            let type_info = match ty {
+                TsType::TsOptionalType(opt) => self.process_optional_type(ts_path, opt)?,
+                TsType::TsRestType(TsRestType { type_ann, .. }) => {
+                    rest_indices.push(types.len());
+                    self.process_type(ts_path, type_ann)?
+                }
+                ty if is_optional => TypeInfo::Optional {
+                    item_type: Box::new(self.process_type(ts_path, ty)?),
+                },
+                ty => self.process_type(ts_path, ty)?,
+            };
+
+            if is_rest {
+                rest_indices.push(types.len());
+            }
+            labels.extend(label);
+            types.push(type_info);
+        }
+
+        let has_rest = !rest_indices.is_empty();
+        if rest_indices
+            .iter()
+            .any(|i| !matches!(types[*i], TypeInfo::Array { .. }))
+        {
+            // we only know the element type of rest elements spelled as arrays
+            // (`...number[]`), not of generic or aliased ones (`...T`) so we
+            // fall back to an array of anything
+            return Ok(TypeInfo::Array {
+                item_type: Box::new(TypeInfo::PrimitiveAny(PrimitiveAny())),
+                readonly: false,
+            });
+        }
+        if rest_indices.iter().any(|i| *i + 1 != types.len()) {
+            // we can't represent leading or middle rest elements
+            // (`[...string[], number]`) as a rust tuple so we fall back to an
+            // array of any of the element types
+            let element_types = types
+                .into_iter()
+                .enumerate()
+                .map(|(i, t)| match t {
+                    TypeInfo::Array { item_type, .. } if rest_indices.contains(&i) => *item_type,
+                    TypeInfo::Optional { item_type } => *item_type,
+                    t => t,
+                })
+                .collect();
+            return Ok(TypeInfo::Array {
+                item_type: Box::new(TypeInfo::Union(Union {
+                    types: element_types,
+                })),
+                readonly: false,
+            });
+        }
+
+        // typescript requires that either all or none of the elements are labeled
+        if labels.len() != types.len() {
+            labels.clear();
+        }
+
+        Ok(TypeInfo::Tuple(Tuple {
+            types,
+            labels,
+            has_rest,
+        }))
+    }
+
+    fn process_type_op(
         &mut self,
         ts_path: &Path,
         TsTypeOperator { op, type_ann, .. }: &TsTypeOperator,
     ) -> Result<TypeInfo, InternalError> {
         Ok(match op {
-            TsTypeOperatorOp::KeyOf => TypeInfo::PrimitiveString(PrimitiveString()),
-            TsTypeOperatorOp::Unique | TsTypeOperatorOp::ReadOnly => {
-                self.process_type(ts_path, &*type_ann)?
-            }
+            TsTypeOperatorOp::KeyOf => TypeInfo::KeyOf {
+                target: Box::new(self.process_type(ts_path, &*type_ann)?),
+            },
+            TsTypeOperatorOp::ReadOnly => match self.process_type(ts_path, &*type_ann)? {
+                TypeInfo::Array { item_type, .. } => TypeInfo::Array {
+                    item_type,
+                    readonly: true,
+                },
+                t => t,
+            },
+            TsTypeOperatorOp::Unique => self.process_type(ts_path, &*type_ann)?,
         })
     }
 
@@ -1408,13 +2064,183 @@ impl TsTypes {
             TsTypeQueryExpr::TsEntityName(ent) => Ok(TypeInfo::TypeQuery(TypeQuery::LookupRef(
                 Source::from(self, ts_path, ent).try_into()?,
             ))),
-            TsTypeQueryExpr::Import(_) => Err(InternalError::with_msg_and_span(
-                "type queries for imports are not supported",
-                query.span(),
-            )),
+            TsTypeQueryExpr::Import(import) => {
+                if self.import_type_namespace(ts_path, import)?.is_some() {
+                    // a module namespace object has no type we can refer to
+                    // outside of a top-level alias (see `process_ts_alias`)
+                    return Ok(TypeInfo::PrimitiveAny(PrimitiveAny()));
+                }
+                Ok(TypeInfo::TypeQuery(TypeQuery::LookupRef(
+                    self.import_type_ref(ts_path, import)?,
+                )))
+            }
+        }
+    }
+
+    /// If `import` is a bare `import("module")` of a module without an
+    /// `export =`, returns the path of the module whose namespace it names.
+    fn import_type_namespace(
+        &mut self,
+        ts_path: &Path,
+        import: &TsImportType,
+    ) -> Result<Option<PathBuf>, InternalError> {
+        if import.qualifier.is_some() {
+            return Ok(None);
+        }
+
+        let file = self.import_type_file(ts_path, import)?;
+        Ok(if self.export_assignment_files.contains(&file) {
+            None
+        } else {
+            Some(file)
+        })
+    }
+
+    /// Returns the path of the module referenced by `import("module")`,
+    /// processing it if we haven't yet.
+    fn import_type_file(
+        &mut self,
+        ts_path: &Path,
+        import: &TsImportType,
+    ) -> Result<PathBuf, InternalError> {
+        let base = path_parent(ts_path)?.to_path_buf();
+
+        // import types may appear within namespaces but the names in the
+        // imported module are relative to its own top level
+        let namespace_stack = std::mem::take(&mut self.namespace_stack);
+        let global_namespace_depth = self.global_namespace_depth.take();
+        let file_result = self.process_module(Some(base), &import.arg.value.to_string());
+        self.namespace_stack = namespace_stack;
+        self.global_namespace_depth = global_namespace_depth;
+        file_result
+    }
+
+    /// Returns a reference to the type named by `import("module").Name`,
+    /// processing the referenced module if we haven't yet.
+    fn import_type_ref(
+        &mut self,
+        ts_path: &Path,
+        import: &TsImportType,
+    ) -> Result<TypeRef, InternalError> {
+        let file = self.import_type_file(ts_path, import)?;
+
+        let referent = match &import.qualifier {
+            Some(TsEntityName::Ident(Ident { sym, .. })) => {
+                TypeName::for_name(file, &sym.to_string())
+            }
+            Some(TsEntityName::TsQualifiedName(qn)) => self.qualified_name_to_type_name(&file, qn),
+            // a bare `import("module")` of a module with an `export =`, which
+            // we record as its default export
+            None => TypeName::default_export_for(file),
+        };
+        let type_params = import
+            .type_args
+            .as_ref()
+            .map(|tps| {
+                tps.params
+                    .iter()
+                    .map(|tp| self.process_type(ts_path, tp))
+                    .collect()
+            })
+            .unwrap_or_else(|| Ok(Default::default()))?;
+
+        Ok(TypeRef {
+            referent,
+            type_params,
+        })
+    }
+
+    fn process_conditional_type(
+        &mut self,
+        ts_path: &Path,
+        TsConditionalType {
+            check_type,
+            extends_type,
+            true_type,
+            false_type,
+            ..
+        }: &TsConditionalType,
+    ) -> Result<TypeInfo, InternalError> {
+        Ok(TypeInfo::Conditional(Conditional {
+            check_type: Box::new(self.process_type(ts_path, check_type)?),
+            extends_type: Box::new(self.process_type(ts_path, extends_type)?),
+            true_type: Box::new(self.process_type(ts_path, true_type)?),
+            false_type: Box::new(self.process_type(ts_path, false_type)?),
+        }))
+    }
+
+    fn process_mapped_key_type(
+        &mut self,
+        ts_path: &Path,
+        key_type: &TsType,
+    ) -> Result<TypeInfo, InternalError> {
+        match key_type {
+            // process_union_type would collapse a union of literal keys into a string
+            TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(
+                TsUnionType { types, .. },
+            )) => Ok(TypeInfo::Union(Union {
+                types: types
+                    .iter()
+                    .map(|t| self.process_type(ts_path, t))
+                    .collect::<Result<Vec<_>, InternalError>>()?,
+            })),
+            _ => self.process_type(ts_path, key_type),
         }
     }
 
+    fn process_mapped_type(
+        &mut self,
+        ts_path: &Path,
+        TsMappedType {
+            readonly,
+            type_param,
+            name_type,
+            optional,
+            type_ann,
+            ..
+        }: &TsMappedType,
+    ) -> Result<TypeInfo, InternalError> {
+        let to_modifier = |m: &Option<TruePlusMinus>| {
+            m.as_ref().map(|m| match m {
+                TruePlusMinus::True | TruePlusMinus::Plus => MappedModifier::Add,
+                TruePlusMinus::Minus => MappedModifier::Remove,
+            })
+        };
+        let key_type = match (&type_param.constraint, name_type) {
+            // without key remapping (`as ...`), we may be able to determine the key set
+            (Some(constraint), None) => self.process_mapped_key_type(ts_path, constraint)?,
+            _ => TypeInfo::PrimitiveString(PrimitiveString()),
+        };
+        let value_type = type_ann
+            .as_ref()
+            .map(|t| self.process_type(ts_path, t))
+            .transpose()?
+            .unwrap_or(TypeInfo::PrimitiveAny(PrimitiveAny()));
+
+        Ok(TypeInfo::MappedType(MappedType {
+            key_name: type_param.name.sym.to_string(),
+            key_type: Box::new(key_type),
+            value_type: Box::new(value_type),
+            optional: to_modifier(optional),
+            readonly: to_modifier(readonly),
+        }))
+    }
+
+    fn process_indexed_access_type(
+        &mut self,
+        ts_path: &Path,
+        TsIndexedAccessType {
+            obj_type,
+            index_type,
+            ..
+        }: &TsIndexedAccessType,
+    ) -> Result<TypeInfo, InternalError> {
+        Ok(TypeInfo::IndexedAccess(IndexedAccess {
+            object_type: Box::new(self.process_type(ts_path, obj_type)?),
+            index_type: Box::new(self.process_type(ts_path, index_type)?),
+        }))
+    }
+
     fn process_type(
         &mut self,
         ts_path: &Path,
@@ -1447,8 +2273,16 @@ impl TsTypes {
             TsType::TsTypePredicate(pred) => self.process_type_predicate(ts_path, pred)?,
             TsType::TsTupleType(tuple) => self.process_tuple(ts_path, tuple)?,
             TsType::TsTypeOperator(op) => self.process_type_op(ts_path, op)?,
-            TsType::TsThisType(_) => TypeInfo::PrimitiveAny(PrimitiveAny()), // TODO: this is clearly silly
+            TsType::TsThisType(_) => TypeInfo::PolymorphicThis(PolymorphicThis()),
             TsType::TsTypeQuery(query) => self.process_type_query(ts_path, query)?,
+            TsType::TsImportType(import) => TypeInfo::Ref(self.import_type_ref(ts_path, import)?),
+            TsType::TsConditionalType(cond) => self.process_conditional_type(ts_path, cond)?,
+            TsType::TsMappedType(mapped) => self.process_mapped_type(ts_path, mapped)?,
+            TsType::TsIndexedAccessType(indexed) => {
+                self.process_indexed_access_type(ts_path, indexed)?
+            }
+            // we don't bind inferred type variables so `infer X` matches anything
+            TsType::TsInferType(_) => TypeInfo::PrimitiveAny(PrimitiveAny()),
             _ => {
                 println!("MISSING {:?} {:?}", ts_path, ts_type);
                 TypeInfo::Ref(TypeRef {
@@ -1499,9 +2333,34 @@ impl TsTypes {
                         setter.param.to_param(ts_path, 0, self)?.type_info,
                     )),
                     TsTypeElement::TsConstructSignatureDecl(_) => None,
-                    TsTypeElement::TsCallSignatureDecl(_) => None, // TODO
+                    TsTypeElement::TsCallSignatureDecl(_) => None,
                 })
             })
+            // members keyed by well-known symbols can't be represented as fields
+            .filter(|member| !matches!(member, Ok((key, _)) if is_well_known_symbol_key(key)))
+            .collect()
+    }
+
+    /// Returns the names of the `readonly` properties of an interface along with those
+    /// that only declare a getter.
+    fn process_interface_readonly_fields(&self, members: &[TsTypeElement]) -> HashSet<String> {
+        let setters: HashSet<_> = members
+            .iter()
+            .filter_map(|el| match el {
+                TsTypeElement::TsSetterSignature(setter) => setter.key(),
+                _ => None,
+            })
+            .collect();
+
+        members
+            .iter()
+            .filter_map(|el| match el {
+                TsTypeElement::TsPropertySignature(prop) if prop.readonly => prop.key(),
+                TsTypeElement::TsGetterSignature(getter) => {
+                    getter.key().filter(|k| !setters.contains(k))
+                }
+                _ => None,
+            })
             .collect()
     }
 
@@ -1522,6 +2381,47 @@ impl TsTypes {
         })
     }
 
+    fn process_interface_callable(
+        &mut self,
+        ts_path: &Path,
+        members: &[TsTypeElement],
+    ) -> Result<Option<FuncGroup>, InternalError> {
+        let overloads = members
+            .iter()
+            .filter_map(|el| match el {
+                TsTypeElement::TsCallSignatureDecl(call) => Some(call.to_func(ts_path, self)),
+                _ => None,
+            })
+            .collect::<Result<Vec<_>, InternalError>>()?;
+
+        Ok(if overloads.is_empty() {
+            None
+        } else {
+            Some(FuncGroup { overloads })
+        })
+    }
+
+    /// Returns the item type of the iterator returned by the method keyed by
+    /// `key` (`[Symbol.iterator]` or `[Symbol.asyncIterator]`), if any.
+    fn process_interface_iterator(
+        &mut self,
+        ts_path: &Path,
+        members: &[TsTypeElement],
+        key: &str,
+        iterator_names: &[&str],
+    ) -> Result<Option<Box<TypeInfo>>, InternalError> {
+        members.iter().find_map_reporting_result(|el| match el {
+            TsTypeElement::TsMethodSignature(method) if method.key().as_deref() == Some(key) => {
+                Some(
+                    method
+                        .to_func(ts_path, self)
+                        .map(|f| Box::new(iterated_type(*f.return_type, iterator_names))),
+                )
+            }
+            _ => None,
+        })
+    }
+
     fn process_ts_interface(
         &mut self,
         ts_path: &Path,
@@ -1547,8 +2447,22 @@ impl TsTypes {
                     })
                     .collect::<Result<Vec<_>, InternalError>>()?,
                 fields: self.process_interface_members(ts_path, &body.body)?,
-                type_params: type_params.type_param_config(),
+                readonly_fields: self.process_interface_readonly_fields(&body.body),
+                type_params: type_params.type_param_config(ts_path, self)?,
                 constructor: self.process_interface_constructor(ts_path, &body.body)?,
+                callable: self.process_interface_callable(ts_path, &body.body)?,
+                iterator: self.process_interface_iterator(
+                    ts_path,
+                    &body.body,
+                    ITERATOR_KEY,
+                    ITERATOR_NAMES,
+                )?,
+                async_iterator: self.process_interface_iterator(
+                    ts_path,
+                    &body.body,
+                    ASYNC_ITERATOR_KEY,
+                    ASYNC_ITERATOR_NAMES,
+                )?,
             }),
         })
     }
@@ -1562,15 +2476,19 @@ impl TsTypes {
             name: self.ns_type_name(TypeName::for_name(ts_path, &id.sym.to_string())),
             is_exported: false,
             info: TypeInfo::Enum(Enum {
-                members: members
-                    .iter()
-                    .scan(
-                        None,
-                        |last_numeric_discriminator, TsEnumMember { id, init, .. }| {
-                            Some(make_enum_member(id, init, last_numeric_discriminator))
-                        },
-                    )
-                    .collect::<Result<Vec<EnumMember>, InternalError>>()?,
+                members: members.iter().fold(
+                    Vec::with_capacity(members.len()),
+                    |mut prior_members,
+                     TsEnumMember {
+                         id: member_id,
+                         init,
+                         ..
+                     }| {
+                        let member = make_enum_member(&id.sym, member_id, init, &prior_members);
+                        prior_members.push(member);
+                        prior_members
+                    },
+                ),
             }),
         })
     }
@@ -1585,13 +2503,28 @@ impl TsTypes {
             ..
         }: &TsTypeAliasDecl,
     ) -> Result<Type, InternalError> {
+        let name = self.ns_type_name(TypeName::for_name(ts_path, &id.sym.to_string()));
+        if let TsType::TsTypeQuery(TsTypeQuery {
+            expr_name: TsTypeQueryExpr::Import(import),
+            ..
+        }) = &**type_ann
+        {
+            if let Some(src) = self.import_type_namespace(ts_path, import)? {
+                return Ok(Type {
+                    name,
+                    is_exported: false,
+                    info: TypeInfo::NamespaceImport(NamespaceImport::All { src }),
+                });
+            }
+        }
+
         let type_info = self.process_type(ts_path, &*type_ann)?;
         Ok(Type {
-            name: self.ns_type_name(TypeName::for_name(ts_path, &id.sym.to_string())),
+            name,
             is_exported: false,
             info: TypeInfo::Alias(Alias {
                 target: Box::new(type_info),
-                type_params: type_params.type_param_config(),
+                type_params: type_params.type_param_config(ts_path, self)?,
             }),
         })
     }
@@ -1620,31 +2553,110 @@ impl TsTypes {
             .filter_map_reporting_result(|member| {
                 Ok(match member {
                     ClassMember::StaticBlock(_) => None,
+                    ClassMember::Method(method)
+                        if !method.is_static
+                            && method.kind == MethodKind::Method
+                            && method.key().as_deref() == Some(ITERATOR_KEY) =>
+                    {
+                        let iterator_type = method.to_member_func(ts_path, self, name)?.return_type;
+                        Some((
+                            MemberKey::Instance(ITERATOR_KEY.to_string()),
+                            Member::Iterator(iterated_type(*iterator_type, ITERATOR_NAMES)),
+                        ))
+                    }
+                    ClassMember::Method(method)
+                        if !method.is_static
+                            && method.kind == MethodKind::Method
+                            && method.key().as_deref() == Some(ASYNC_ITERATOR_KEY) =>
+                    {
+                        let iterator_type = method.to_member_func(ts_path, self, name)?.return_type;
+                        Some((
+                            MemberKey::Instance(ASYNC_ITERATOR_KEY.to_string()),
+                            Member::AsyncIterator(iterated_type(
+                                *iterator_type,
+                                ASYNC_ITERATOR_NAMES,
+                            )),
+                        ))
+                    }
+                    // other well-known symbols can't be bound by name
+                    ClassMember::Method(method)
+                        if method.key().map_or(false, |k| is_well_known_symbol_key(&k)) =>
+                    {
+                        None
+                    }
+                    ClassMember::ClassProp(prop)
+                        if prop.key().map_or(false, |k| is_well_known_symbol_key(&k)) =>
+                    {
+                        None
+                    }
                     ClassMember::Constructor(ctor) => Some((
-                        make_key(ctor)?,
+                        MemberKey::Instance(make_key(ctor)?),
                         Member::Constructor(CtorGroup {
                             overloads: vec![ctor.to_ctor(ts_path, self)?],
                         }),
                     )),
+                    ClassMember::Method(method)
+                        if method.is_static && method.kind == MethodKind::Method =>
+                    {
+                        Some((
+                            MemberKey::Static(make_key(method)?),
+                            Member::StaticMethod(FuncGroup {
+                                overloads: vec![method.to_func(ts_path, self)?],
+                            }),
+                        ))
+                    }
+                    ClassMember::Method(method)
+                        if method.is_static && method.kind == MethodKind::Getter =>
+                    {
+                        Some((
+                            MemberKey::Static(make_key(method)?),
+                            // getters without a corresponding setter are readonly, which we
+                            // reconcile when merging accessors below
+                            Member::StaticProperty(Property {
+                                readonly: true,
+                                type_info: *method.to_func(ts_path, self)?.return_type,
+                            }),
+                        ))
+                    }
+                    ClassMember::Method(method)
+                        if method.is_static && method.kind == MethodKind::Setter =>
+                    {
+                        Some((
+                            MemberKey::Static(make_key(method)?),
+                            Member::StaticProperty(
+                                method
+                                    .to_func(ts_path, self)?
+                                    .params
+                                    .pop()
+                                    .map(|p| p.type_info)
+                                    .unwrap_or_else(|| TypeInfo::PrimitiveAny(PrimitiveAny()))
+                                    .into(),
+                            ),
+                        ))
+                    }
                     ClassMember::Method(method) if method.kind == MethodKind::Method => Some((
-                        make_key(method)?,
+                        MemberKey::Instance(make_key(method)?),
                         Member::Method(FuncGroup {
                             overloads: vec![method.to_member_func(ts_path, self, name)?],
                         }),
                     )),
                     ClassMember::Method(method) if method.kind == MethodKind::Getter => Some((
-                        make_key(method)?,
-                        Member::Property(*method.to_member_func(ts_path, self, name)?.return_type),
+                        MemberKey::Instance(make_key(method)?),
+                        Member::Property(Property {
+                            readonly: true,
+                            type_info: *method.to_member_func(ts_path, self, name)?.return_type,
+                        }),
                     )),
                     ClassMember::Method(method) if method.kind == MethodKind::Setter => Some((
-                        make_key(method)?,
+                        MemberKey::Instance(make_key(method)?),
                         Member::Property(
                             method
                                 .to_member_func(ts_path, self, name)?
                                 .params
                                 .pop()
                                 .map(|p| p.type_info)
-                                .unwrap_or_else(|| TypeInfo::PrimitiveAny(PrimitiveAny())),
+                                .unwrap_or_else(|| TypeInfo::PrimitiveAny(PrimitiveAny()))
+                                .into(),
                         ),
                     )),
                     ClassMember::Method(_) => None,
@@ -1655,10 +2667,21 @@ impl TsTypes {
                             .map(|a| a != Accessibility::Private)
                             .unwrap_or(true) =>
                     {
-                        Some((
-                            make_key(prop)?,
-                            Member::Property(prop.to_type_info(ts_path, self)?),
-                        ))
+                        let prop_info = Property {
+                            readonly: prop.readonly,
+                            type_info: prop.to_type_info(ts_path, self)?,
+                        };
+                        Some(if prop.is_static {
+                            (
+                                MemberKey::Static(make_key(prop)?),
+                                Member::StaticProperty(prop_info),
+                            )
+                        } else {
+                            (
+                                MemberKey::Instance(make_key(prop)?),
+                                Member::Property(prop_info),
+                            )
+                        })
                     }
                     ClassMember::ClassProp(_) => None,
                     ClassMember::PrivateProp(_) => None,
@@ -1666,10 +2689,10 @@ impl TsTypes {
                     ClassMember::Empty(_) => None,
                 })
             })
-            .collect::<Result<Vec<(String, Member)>, InternalError>>()?;
+            .collect::<Result<Vec<(MemberKey, Member)>, InternalError>>()?;
 
         let members = members.into_iter().fold(
-            HashMap::new() as HashMap<String, Member>,
+            HashMap::new() as HashMap<MemberKey, Member>,
             |mut final_members, (name, member)| {
                 final_members
                     .entry(name)
@@ -1677,9 +2700,20 @@ impl TsTypes {
                         (Member::Method(cur_fg), Member::Method(fg)) => {
                             cur_fg.overloads.extend(fg.overloads.iter().cloned());
                         }
+                        (Member::StaticMethod(cur_fg), Member::StaticMethod(fg)) => {
+                            cur_fg.overloads.extend(fg.overloads.iter().cloned());
+                        }
                         (Member::Constructor(cur_cg), Member::Constructor(cg)) => {
                             cur_cg.overloads.extend(cg.overloads.iter().cloned());
                         }
+                        // a property with both a getter and a setter is writable
+                        (Member::Property(cur_prop), Member::Property(prop))
+                        | (Member::StaticProperty(cur_prop), Member::StaticProperty(prop)) => {
+                            cur_prop.readonly = cur_prop.readonly && prop.readonly;
+                        }
+                        // statics are keyed apart from instance members so
+                        // anything else is a conflicting redeclaration, which
+                        // typescript rejects
                         _ => {}
                     })
                     .or_insert_with(|| member.clone());
@@ -1694,7 +2728,7 @@ impl TsTypes {
                 .iter()
                 .map(|i| Source::from(self, ts_path, i).try_into())
                 .collect::<Result<Vec<_>, InternalError>>()?,
-            type_params: type_params.type_param_config(),
+            type_params: type_params.type_param_config(ts_path, self)?,
         }))
     }
 
@@ -1750,6 +2784,20 @@ impl TsTypes {
         })
     }
 
+    /// Declarations within `declare global` blocks live on the global object
+    /// rather than in our js module. We otherwise treat them like any other
+    /// declarations in the file.
+    fn process_global_body(&mut self, ts_path: &Path, body: Option<&TsNamespaceBody>) {
+        let depth = self.namespace_stack.last().map(|ns| ns.len()).unwrap_or(0);
+        let prev_depth = self.global_namespace_depth.replace(depth);
+
+        if let Some(TsNamespaceBody::TsModuleBlock(block)) = body {
+            self.process_module_items(ts_path, &block.body);
+        }
+
+        self.global_namespace_depth = prev_depth;
+    }
+
     fn process_namespace_body(
         &mut self,
         ts_path: &Path,
@@ -1769,12 +2817,21 @@ impl TsTypes {
             // with access to the surrounding types environment
 
             if let Some(TsNamespaceBody::TsModuleBlock(block)) = body {
+                // if the declaration augments an existing module, make sure that we've processed
+                // the original module first so the augmentations are merged into its declarations
+                // rather than being shadowed by them.
+                // errors are reported when we process the block below.
+                let augmented_module = self.process_module(None, &name).ok();
+
+                let prev_augmented_module =
+                    std::mem::replace(&mut self.augmented_module, augmented_module);
                 let file_result = self.process_module_with_items(
                     None, // TODO: should we resolve relative to ts_path?
                     &name,
                     true,
                     |_, _| Ok(block.body.clone()),
                 );
+                self.augmented_module = prev_augmented_module;
 
                 let file = match file_result {
                     Ok(file) => file,
@@ -1844,6 +2901,13 @@ impl TsTypes {
                 .iter()
                 .map(|var| self.process_var(ts_path, var))
                 .collect(),
+            Decl::TsModule(TsModuleDecl {
+                global: true, body, ..
+            }) => {
+                self.process_global_body(ts_path, body.as_ref());
+
+                Default::default()
+            }
             Decl::TsModule(TsModuleDecl {
                 id, declare, body, ..
             }) => {
@@ -2075,14 +3139,15 @@ impl TsTypes {
                 println!("export default expr, {:?}", _decl);
             }
             ModuleDecl::ExportAll(decl) => self.process_export_all(ts_path, decl),
-            ModuleDecl::TsImportEquals(_decl) => {
-                println!("import equals, {:?}", _decl);
-            }
+            ModuleDecl::TsImportEquals(decl) => self.process_import_equals(ts_path, decl),
             ModuleDecl::TsExportAssignment(decl) => {
+                self.export_assignment_files.insert(ts_path.to_path_buf());
                 self.export_default_alias(ts_path, decl);
             }
-            ModuleDecl::TsNamespaceExport(_decl) => {
-                println!("export namespace, {:?}", _decl);
+            ModuleDecl::TsNamespaceExport(decl) => {
+                self.js_globals
+                    .umd_namespaces_by_file
+                    .insert(ts_path.to_path_buf(), decl.id.sym.to_string());
             }
         }
     }
@@ -2092,8 +3157,8 @@ impl TsTypes {
             self.process_decl(ts_path, decl)
                 .into_iter()
                 .for_each(|typ| {
-                    let typ = if self.currently_in_namespace() {
-                        // decls in namespaces or modules are exported by default
+                    let typ = if self.currently_in_namespace() || self.currently_in_global() {
+                        // decls in namespaces, modules, or the global scope are exported by default
                         typ.map(|mut t| {
                             t.is_exported = true;
                             t
@@ -2101,6 +3166,9 @@ impl TsTypes {
                     } else {
                         typ
                     };
+                    if let Ok(t) = &typ {
+                        self.record_global_namespace(ts_path, &t.name.name);
+                    }
                     self.set_type_for_file(ts_path, typ)
                 });
         }
@@ -2109,6 +3177,67 @@ impl TsTypes {
     fn currently_in_namespace(&self) -> bool {
         !self.namespace_stack.is_empty()
     }
+
+    fn currently_in_global(&self) -> bool {
+        self.global_namespace_depth.is_some()
+    }
+
+    /// If we are within a `declare global` block, records the js namespace
+    /// of the type named `name` relative to the global object.
+    fn record_global_namespace(&mut self, ts_path: &Path, name: &TypeIdent) {
+        let depth = match self.global_namespace_depth {
+            Some(depth) => depth,
+            None => return,
+        };
+        let name_parts = match name {
+            TypeIdent::Name(n) => vec![n.clone()],
+            TypeIdent::QualifiedName(parts) => parts.clone(),
+            _ => return,
+        };
+        let js_namespace = self
+            .namespace_stack
+            .last()
+            .map(|ns| ns[depth..].to_vec())
+            .unwrap_or_default();
+
+        self.js_globals
+            .global_namespaces_by_name_by_file
+            .entry(ts_path.to_path_buf())
+            .or_default()
+            .insert(name_parts, js_namespace);
+    }
+}
+
+/// A triple-slash directive (`/// <reference ... />`) pulling in the declarations of another file
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ReferenceDirective {
+    /// `/// <reference path="..." />`, relative to the referencing file
+    Path(String),
+    /// `/// <reference types="..." />`, resolved as a package (preferring `@types`)
+    Types(String),
+}
+
+impl ReferenceDirective {
+    /// Parses the text of a line comment (after the leading `//`) as a reference directive
+    fn parse(comment: &str) -> Option<ReferenceDirective> {
+        let directive = comment.strip_prefix('/')?.trim();
+        let attrs = directive
+            .strip_prefix("<reference")?
+            .trim_end()
+            .strip_suffix("/>")?;
+
+        let attr_value = |attr: &str| {
+            let (_, rest) = attrs.split_once(attr)?;
+            let rest = rest.trim_start().strip_prefix('=')?.trim_start();
+            let quote = rest.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+            let (value, _) = rest[1..].split_once(quote)?;
+            Some(value.to_string())
+        };
+
+        attr_value("path")
+            .map(ReferenceDirective::Path)
+            .or_else(|| attr_value("types").map(ReferenceDirective::Types))
+    }
 }
 
 fn path_parent(path: &Path) -> Result<&Path, InternalError> {
@@ -2119,42 +3248,132 @@ fn path_parent(path: &Path) -> Result<&Path, InternalError> {
 }
 
 fn make_enum_member(
+    enum_name: &str,
     id: &TsEnumMemberId,
     init: &Option<Box<Expr>>,
-    last_numeric_discriminator: &mut Option<f64>,
-) -> Result<EnumMember, InternalError> {
-    Ok(EnumMember {
+    prior_members: &[EnumMember],
+) -> EnumMember {
+    let value = match init {
+        // we can't represent the values of initializers we can't evaluate (e.g.
+        // function calls) but can still represent the member itself
+        Some(init) => eval_enum_initializer(init, enum_name, prior_members).ok(),
+        // members without initializers auto-increment from the prior member,
+        // starting at 0
+        None => match prior_members.last() {
+            None => Some(EnumValue::Num(0.0)),
+            Some(EnumMember {
+                value: Some(EnumValue::Num(n)),
+                ..
+            }) => Some(EnumValue::Num(n + 1.0)),
+            Some(_) => None,
+        },
+    };
+
+    EnumMember {
         id: match id {
             TsEnumMemberId::Ident(ident) => ident.sym.to_string(),
             TsEnumMemberId::Str(s) => s.value.to_string(),
         },
-        value: init
-            .as_ref()
-            .and_then(|v| match &**v {
-                Expr::Lit(l) => match l {
-                    Lit::Str(s) => Some(Ok(EnumValue::Str(s.value.to_string()))),
-                    Lit::Num(n) => {
-                        *last_numeric_discriminator = Some(n.value);
-                        Some(Ok(EnumValue::Num(n.value)))
-                    }
-                    _ => None,
-                },
-                _ => Some(Err(InternalError::with_msg_and_span(
-                    "enums with non-literal initializers not supported",
-                    v.span(),
-                ))),
-            })
-            .or_else(|| {
-                last_numeric_discriminator.map(|last| {
-                    // if we have a prior EnumValue::Num member, use the prior
-                    // value + 1 as our enum value
-                    let our_init = last + 1.0;
-                    *last_numeric_discriminator = Some(our_init);
-                    Ok(EnumValue::Num(our_init))
-                })
-            })
-            .transpose()?,
-    })
+        value,
+    }
+}
+
+/// Folds a constant enum member initializer, which may reference prior members
+/// of the same enum, `enum_name`, either directly (`A`) or qualified (`E.A`).
+fn eval_enum_initializer(
+    expr: &Expr,
+    enum_name: &str,
+    prior_members: &[EnumMember],
+) -> Result<EnumValue, InternalError> {
+    let unsupported = || {
+        InternalError::with_msg_and_span(
+            "enums with non-constant initializers not supported",
+            expr.span(),
+        )
+    };
+    let eval = |e: &Expr| eval_enum_initializer(e, enum_name, prior_members);
+    let eval_num = |e: &Expr| match eval(e)? {
+        EnumValue::Num(n) => Ok(n),
+        _ => Err(unsupported()),
+    };
+    let prior_value = |member_name: &str| {
+        prior_members
+            .iter()
+            .find(|m| m.id == member_name)
+            .and_then(|m| m.value.clone())
+            .ok_or_else(unsupported)
+    };
+
+    let to_js_string = |v: EnumValue| match v {
+        EnumValue::Str(s) => s,
+        EnumValue::Num(n) => n.to_string(),
+        EnumValue::Bool(b) => b.to_string(),
+    };
+
+    match expr {
+        Expr::Lit(Lit::Num(n)) => Ok(EnumValue::Num(n.value)),
+        Expr::Lit(Lit::Str(s)) => Ok(EnumValue::Str(s.value.to_string())),
+        Expr::Paren(ParenExpr { expr, .. }) => eval(expr),
+        Expr::Ident(Ident { sym, .. }) => prior_value(sym),
+        Expr::Member(MemberExpr {
+            obj: ExprOrSuper::Expr(obj),
+            prop,
+            computed,
+            ..
+        }) => match (&**obj, &**prop, *computed) {
+            (Expr::Ident(Ident { sym: obj, .. }), Expr::Ident(Ident { sym: prop, .. }), false)
+            | (
+                Expr::Ident(Ident { sym: obj, .. }),
+                Expr::Lit(Lit::Str(Str { value: prop, .. })),
+                true,
+            ) if &**obj == enum_name => prior_value(prop),
+            _ => Err(unsupported()),
+        },
+        Expr::Unary(UnaryExpr { op, arg, .. }) => {
+            let n = eval_num(arg)?;
+            match op {
+                UnaryOp::Minus => Ok(EnumValue::Num(-n)),
+                UnaryOp::Plus => Ok(EnumValue::Num(n)),
+                UnaryOp::Tilde => Ok(EnumValue::Num(f64::from(!to_int32(n)))),
+                _ => Err(unsupported()),
+            }
+        }
+        Expr::Bin(BinExpr {
+            op, left, right, ..
+        }) => match (op, eval(left)?, eval(right)?) {
+            (BinaryOp::Add, EnumValue::Str(l), r) => Ok(EnumValue::Str(l + &to_js_string(r))),
+            (BinaryOp::Add, l, EnumValue::Str(r)) => Ok(EnumValue::Str(to_js_string(l) + &r)),
+            (op, EnumValue::Num(l), EnumValue::Num(r)) => {
+                let shift = to_int32(r) as u32 & 0x1f;
+                Ok(EnumValue::Num(match op {
+                    BinaryOp::Add => l + r,
+                    BinaryOp::Sub => l - r,
+                    BinaryOp::Mul => l * r,
+                    BinaryOp::Div => l / r,
+                    BinaryOp::Mod => l % r,
+                    BinaryOp::Exp => l.powf(r),
+                    BinaryOp::BitOr => f64::from(to_int32(l) | to_int32(r)),
+                    BinaryOp::BitAnd => f64::from(to_int32(l) & to_int32(r)),
+                    BinaryOp::BitXor => f64::from(to_int32(l) ^ to_int32(r)),
+                    BinaryOp::LShift => f64::from(to_int32(l).wrapping_shl(shift)),
+                    BinaryOp::RShift => f64::from(to_int32(l) >> shift),
+                    BinaryOp::ZeroFillRShift => f64::from((to_int32(l) as u32) >> shift),
+                    _ => return Err(unsupported()),
+                }))
+            }
+            _ => Err(unsupported()),
+        },
+        _ => Err(unsupported()),
+    }
+}
+
+/// Converts a number to a 32-bit integer the way javascript's bitwise operators do
+fn to_int32(n: f64) -> i32 {
+    if n.is_finite() {
+        (n.trunc() % 4_294_967_296.0) as i64 as u32 as i32
+    } else {
+        0
+    }
 }
 
 #[cfg(test)]
@@ -2234,10 +3453,18 @@ mod test {
             {
                 assert!(c.super_class.is_none());
                 assert_eq!(c.members.len(), 3);
-                assert!(c.members.contains_key("x"));
-                assert!(c.members.contains_key("y"));
-                assert!(c.members.contains_key("z"));
-                assert!(!c.members.contains_key("n"));
+                assert!(c
+                    .members
+                    .contains_key(&MemberKey::Instance("x".to_string())));
+                assert!(c
+                    .members
+                    .contains_key(&MemberKey::Instance("y".to_string())));
+                assert!(c
+                    .members
+                    .contains_key(&MemberKey::Instance("z".to_string())));
+                assert!(!c
+                    .members
+                    .contains_key(&MemberKey::Instance("n".to_string())));
             }
         )
     }
@@ -2257,43 +3484,99 @@ mod test {
             {
                 assert!(c.super_class.is_none());
                 assert_eq!(c.members.len(), 1);
-                let thing = c.members.get("thing");
+                let thing = c.members.get(&MemberKey::Instance("thing".to_string()));
                 assert!(thing.is_some());
                 let thing = thing.unwrap();
                 assert_eq!(
                     *thing,
-                    Member::Property(TypeInfo::PrimitiveNumber(PrimitiveNumber()))
+                    Member::Property(TypeInfo::PrimitiveNumber(PrimitiveNumber()).into())
                 );
             }
         )
     }
 
-    fn test_first_fn_param(
-        ts_code: &str,
-        fn_name: &str,
-        expected_param: &Param,
-    ) -> Result<(), Error> {
-        let fn_name = TypeIdent::Name(fn_name.to_string());
-        test_exported_type!(ts_code, fn_name, TypeInfo::FuncGroup(fg), {
-            assert_eq!(fg.overloads.len(), 1);
-            let f = fg.overloads.first().unwrap();
-            assert_eq!(f.params.len(), 1);
-            assert_eq!(f.params.first().unwrap(), expected_param);
-        })
-    }
-
     #[test]
-    fn test_fn_param_array_destructure() -> Result<(), Error> {
-        test_first_fn_param(
-            r#"export declare function arrayPat([a, b]: [string, number]);"#,
-            "arrayPat",
-            &Param {
-                name: "arg0".to_string(),
-                type_info: TypeInfo::Tuple(Tuple {
+    fn test_class_static_members() -> Result<(), Error> {
+        test_exported_type!(
+            r#"export declare class A {
+                static create(n: number): A;
+                static count: number;
+                name: string;
+            }"#,
+            "A",
+            TypeInfo::Class(c),
+            {
+                assert_eq!(c.members.len(), 3);
+                assert!(matches!(
+                    c.members.get(&MemberKey::Static("create".to_string())),
+                    Some(Member::StaticMethod(FuncGroup { overloads })) if overloads[0].class_name.is_none()
+                ));
+                assert_eq!(
+                    c.members.get(&MemberKey::Static("count".to_string())),
+                    Some(&Member::StaticProperty(
+                        TypeInfo::PrimitiveNumber(PrimitiveNumber()).into()
+                    ))
+                );
+                assert_eq!(
+                    c.members.get(&MemberKey::Instance("name".to_string())),
+                    Some(&Member::Property(
+                        TypeInfo::PrimitiveString(PrimitiveString()).into()
+                    ))
+                );
+            }
+        )
+    }
+
+    #[test]
+    fn test_class_static_and_instance_members_with_same_name() -> Result<(), Error> {
+        test_exported_type!(
+            r#"export declare class A {
+                static from(s: string): A;
+                from(): string;
+            }"#,
+            "A",
+            TypeInfo::Class(c),
+            {
+                assert_eq!(c.members.len(), 2);
+                assert!(matches!(
+                    c.members.get(&MemberKey::Static("from".to_string())),
+                    Some(Member::StaticMethod(_))
+                ));
+                assert!(matches!(
+                    c.members.get(&MemberKey::Instance("from".to_string())),
+                    Some(Member::Method(_))
+                ));
+            }
+        )
+    }
+
+    fn test_first_fn_param(
+        ts_code: &str,
+        fn_name: &str,
+        expected_param: &Param,
+    ) -> Result<(), Error> {
+        let fn_name = TypeIdent::Name(fn_name.to_string());
+        test_exported_type!(ts_code, fn_name, TypeInfo::FuncGroup(fg), {
+            assert_eq!(fg.overloads.len(), 1);
+            let f = fg.overloads.first().unwrap();
+            assert_eq!(f.params.len(), 1);
+            assert_eq!(f.params.first().unwrap(), expected_param);
+        })
+    }
+
+    #[test]
+    fn test_fn_param_array_destructure() -> Result<(), Error> {
+        test_first_fn_param(
+            r#"export declare function arrayPat([a, b]: [string, number]);"#,
+            "arrayPat",
+            &Param {
+                name: "arg0".to_string(),
+                type_info: TypeInfo::Tuple(Tuple {
                     types: vec![
                         TypeInfo::PrimitiveString(PrimitiveString()),
                         TypeInfo::PrimitiveNumber(PrimitiveNumber()),
                     ],
+                    ..Default::default()
                 }),
                 is_variadic: false,
             },
@@ -2340,8 +3623,12 @@ mod test {
                     indexer: None,
                     extends: Default::default(),
                     fields,
+                    readonly_fields: Default::default(),
                     type_params: Default::default(),
                     constructor: None,
+                    callable: None,
+                    iterator: None,
+                    async_iterator: None,
                 }),
                 is_variadic: false,
             },
@@ -2405,56 +3692,1080 @@ mod test {
                 let getter = getter.unwrap();
                 assert_eq!(*getter, TypeInfo::PrimitiveString(PrimitiveString()));
 
-                let setter = i.fields.get("setter");
-                assert!(setter.is_some());
-                let setter = setter.unwrap();
-                assert_eq!(*setter, TypeInfo::PrimitiveString(PrimitiveString()));
+                let setter = i.fields.get("setter");
+                assert!(setter.is_some());
+                let setter = setter.unwrap();
+                assert_eq!(*setter, TypeInfo::PrimitiveString(PrimitiveString()));
+            }
+        )
+    }
+
+    #[test]
+    fn test_interface_indexer() -> Result<(), Error> {
+        test_exported_type!(
+            r#"export interface A {
+                [n: string]: number;
+            }"#,
+            "A",
+            TypeInfo::Interface(i),
+            {
+                assert!(i.fields.is_empty());
+                assert!(i.indexer.is_some());
+                let indexer = i.indexer.as_ref().unwrap();
+                assert_eq!(
+                    *indexer.type_info,
+                    TypeInfo::PrimitiveNumber(PrimitiveNumber())
+                );
+            }
+        )
+    }
+
+    #[test]
+    fn test_interface_constructor() -> Result<(), Error> {
+        test_exported_type!(
+            r#"export interface A {
+                new (n: number): A;
+            }"#,
+            "A",
+            TypeInfo::Interface(i),
+            {
+                assert!(i.fields.is_empty());
+                assert!(i.indexer.is_none());
+                assert!(i.constructor.is_some());
+                let ctor = i.constructor.as_ref().unwrap();
+                assert_eq!(ctor.params.len(), 1);
+                let param = ctor.params.first().unwrap();
+                assert_eq!(
+                    *param,
+                    Param {
+                        name: "n".to_string(),
+                        type_info: TypeInfo::PrimitiveNumber(PrimitiveNumber()),
+                        is_variadic: false,
+                    }
+                );
+            }
+        )
+    }
+
+    #[test]
+    fn test_interface_call_signature() -> Result<(), Error> {
+        test_exported_type!(
+            r#"export interface A {
+                (n: number): string;
+                (s: string): string;
+                count: number;
+            }"#,
+            "A",
+            TypeInfo::Interface(i),
+            {
+                assert_eq!(i.fields.len(), 1);
+                assert!(i.fields.contains_key("count"));
+                assert!(i.constructor.is_none());
+                let callable = i.callable.as_ref().unwrap();
+                assert_eq!(callable.overloads.len(), 2);
+                assert!(callable
+                    .overloads
+                    .iter()
+                    .all(|f| *f.return_type == TypeInfo::PrimitiveString(PrimitiveString())));
+            }
+        )
+    }
+
+    #[test]
+    fn test_import_equals_qualified_name() -> Result<(), Error> {
+        test_exported_type!(
+            r#"export namespace A.B {
+                export interface C {
+                    n: number;
+                }
+            }
+            export import X = A.B.C;"#,
+            "X",
+            TypeInfo::Alias(a),
+            {
+                assert!(matches!(
+                    *a.target,
+                    TypeInfo::Ref(TypeRef {
+                        referent: TypeName {
+                            name: TypeIdent::QualifiedName(ref parts),
+                            ..
+                        },
+                        ..
+                    }) if *parts == vec!["A".to_string(), "B".to_string(), "C".to_string()]
+                ));
+            }
+        )
+    }
+
+    #[test]
+    fn test_import_equals_namespace() -> Result<(), Error> {
+        test_exported_type!(
+            r#"export namespace A.B {
+                export interface C {
+                    n: number;
+                }
+            }
+            import NS = A.B;
+            export interface D {
+                c: NS.C;
+            }"#,
+            "D",
+            TypeInfo::Interface(i),
+            {
+                assert!(matches!(
+                    i.fields.get("c").unwrap(),
+                    TypeInfo::Ref(TypeRef {
+                        referent: TypeName {
+                            name: TypeIdent::QualifiedName(parts),
+                            ..
+                        },
+                        ..
+                    }) if *parts == vec!["A".to_string(), "B".to_string(), "C".to_string()]
+                ));
+            }
+        )
+    }
+
+    #[test]
+    fn test_import_equals_require() -> Result<(), Error> {
+        let test_path: &Path = Path::new("/test.d.ts");
+        let mut fs: MemFs = Default::default();
+        fs.set_cwd(Path::new("/"));
+        fs.add_file_at(
+            Path::new("/dep.d.ts"),
+            r#"declare class Dep {
+                n: number;
+            }
+            export = Dep;"#
+                .to_string(),
+        );
+        fs.add_file_at(
+            test_path,
+            r#"import dep = require("./dep");
+            export declare function f(d: dep): void;"#
+                .to_string(),
+        );
+
+        let mut tbnbf = TsTypes::parse(Arc::new(fs) as ArcFs, &test_path.to_string_lossy())?;
+        let types = tbnbf.remove(test_path).unwrap();
+        let dep = types.get(&TypeIdent::Name("dep".to_string())).unwrap();
+
+        assert!(!dep.is_exported);
+        assert_eq!(
+            dep.info,
+            TypeInfo::NamespaceImport(NamespaceImport::Default {
+                src: PathBuf::from("/dep.d.ts")
+            })
+        );
+
+        let dep_types = tbnbf.remove(Path::new("/dep.d.ts")).unwrap();
+        assert!(dep_types.contains_key(&TypeIdent::DefaultExport()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_equals_require_qualified() -> Result<(), Error> {
+        let test_path: &Path = Path::new("/test.d.ts");
+        let dep_path: &Path = Path::new("/dep.d.ts");
+        let mut fs: MemFs = Default::default();
+        fs.set_cwd(Path::new("/"));
+        fs.add_file_at(
+            dep_path,
+            r#"declare class Dep {
+                n: number;
+            }
+            declare namespace Dep {
+                interface Inner {
+                    v: string;
+                }
+            }
+            export = Dep;"#
+                .to_string(),
+        );
+        fs.add_file_at(
+            test_path,
+            r#"import dep = require("./dep");
+            export declare function f(i: dep.Inner): void;"#
+                .to_string(),
+        );
+
+        let mut tbnbf = TsTypes::parse(Arc::new(fs) as ArcFs, &test_path.to_string_lossy())?;
+        let types = tbnbf.remove(test_path).unwrap();
+        let f = types.get(&TypeIdent::Name("f".to_string())).unwrap();
+        if let TypeInfo::FuncGroup(fg) = &f.info {
+            assert_eq!(
+                fg.overloads[0].params[0].type_info,
+                TypeInfo::Ref(TypeRef {
+                    referent: TypeName::for_qualified_name(
+                        dep_path.to_path_buf(),
+                        vec!["Dep".to_string(), "Inner".to_string()]
+                    ),
+                    type_params: Default::default(),
+                })
+            );
+        } else {
+            assert!(false);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_types() -> Result<(), Error> {
+        let test_path: &Path = Path::new("/test.d.ts");
+        let dep_path: &Path = Path::new("/dep.d.ts");
+        let mut fs: MemFs = Default::default();
+        fs.set_cwd(Path::new("/"));
+        fs.add_file_at(
+            dep_path,
+            r#"export interface Bar<T> {
+                value: T;
+            }
+            export declare namespace Inner {
+                interface Baz {
+                    n: number;
+                }
+            }
+            export declare const version: string;"#
+                .to_string(),
+        );
+        fs.add_file_at(
+            test_path,
+            r#"export declare namespace Outer {
+                function f(b: import("./dep").Bar<number>): import("./dep").Inner.Baz;
+            }
+            export type Version = typeof import("./dep").version;"#
+                .to_string(),
+        );
+
+        let mut tbnbf = TsTypes::parse(Arc::new(fs) as ArcFs, &test_path.to_string_lossy())?;
+
+        // the imported module's names are not qualified by the namespace we
+        // first referenced it from
+        let dep_types = tbnbf.remove(dep_path).unwrap();
+        assert!(dep_types.contains_key(&TypeIdent::Name("Bar".to_string())));
+
+        let types = tbnbf.remove(test_path).unwrap();
+        let f = &types[&TypeIdent::QualifiedName(vec!["Outer".to_string(), "f".to_string()])];
+        if let TypeInfo::FuncGroup(fg) = &f.info {
+            let func = &fg.overloads[0];
+            assert_eq!(
+                func.params[0].type_info,
+                TypeInfo::Ref(TypeRef {
+                    referent: TypeName::for_name(dep_path, "Bar"),
+                    type_params: vec![TypeInfo::PrimitiveNumber(PrimitiveNumber())],
+                })
+            );
+            assert_eq!(
+                *func.return_type,
+                TypeInfo::Ref(TypeRef {
+                    referent: TypeName::for_qualified_name(
+                        dep_path.to_path_buf(),
+                        vec!["Inner".to_string(), "Baz".to_string()]
+                    ),
+                    type_params: Default::default(),
+                })
+            );
+        } else {
+            assert!(false);
+        }
+
+        assert!(matches!(
+            &types[&TypeIdent::Name("Version".to_string())].info,
+            TypeInfo::Alias(Alias { target, .. })
+                if **target == TypeInfo::TypeQuery(TypeQuery::LookupRef(TypeRef {
+                    referent: TypeName::for_name(dep_path, "version"),
+                    type_params: Default::default(),
+                }))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_bare_import_types() -> Result<(), Error> {
+        let test_path: &Path = Path::new("/test.d.ts");
+        let esm_path: &Path = Path::new("/esm.d.ts");
+        let cjs_path: &Path = Path::new("/cjs.d.ts");
+        let mut fs: MemFs = Default::default();
+        fs.set_cwd(Path::new("/"));
+        fs.add_file_at(
+            esm_path,
+            r#"export declare function f(): void;"#.to_string(),
+        );
+        fs.add_file_at(
+            cjs_path,
+            r#"declare class Cjs {}
+            export = Cjs;"#
+                .to_string(),
+        );
+        fs.add_file_at(
+            test_path,
+            r#"export type Esm = typeof import("./esm");
+            export type Cjs = typeof import("./cjs");
+            export declare function g(m: typeof import("./esm")): void;"#
+                .to_string(),
+        );
+
+        let types = TsTypes::parse(Arc::new(fs) as ArcFs, &test_path.to_string_lossy())?
+            .remove(test_path)
+            .unwrap();
+
+        assert_eq!(
+            types[&TypeIdent::Name("Esm".to_string())].info,
+            TypeInfo::NamespaceImport(NamespaceImport::All {
+                src: esm_path.to_path_buf(),
+            })
+        );
+        assert!(matches!(
+            &types[&TypeIdent::Name("Cjs".to_string())].info,
+            TypeInfo::Alias(Alias { target, .. })
+                if **target == TypeInfo::TypeQuery(TypeQuery::LookupRef(TypeRef {
+                    referent: TypeName::default_export_for(cjs_path.to_path_buf()),
+                    type_params: Default::default(),
+                }))
+        ));
+        if let TypeInfo::FuncGroup(fg) = &types[&TypeIdent::Name("g".to_string())].info {
+            assert_eq!(
+                fg.overloads[0].params[0].type_info,
+                TypeInfo::PrimitiveAny(PrimitiveAny())
+            );
+        } else {
+            assert!(false);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_declare_global() -> Result<(), Error> {
+        let test_path: &Path = Path::new("/test.d.ts");
+        let mut fs: MemFs = Default::default();
+        fs.set_cwd(Path::new("/"));
+        fs.add_file_at(
+            test_path,
+            r#"export declare function local(): void;
+            declare global {
+                function topLevel(): void;
+                namespace MyLib {
+                    function nested(): void;
+                }
+            }"#
+            .to_string(),
+        );
+
+        let (mut tbnbf, js_globals) =
+            TsTypes::parse_with_js_globals(Arc::new(fs) as ArcFs, &test_path.to_string_lossy())?;
+        let types = tbnbf.remove(test_path).unwrap();
+        assert!(types[&TypeIdent::Name("topLevel".to_string())].is_exported);
+
+        let globals = &js_globals.global_namespaces_by_name_by_file[test_path];
+        assert_eq!(globals.len(), 2);
+        assert_eq!(globals[&vec!["topLevel".to_string()]], Vec::<String>::new());
+        assert_eq!(
+            globals[&vec!["MyLib".to_string(), "nested".to_string()]],
+            vec!["MyLib".to_string()]
+        );
+        assert!(js_globals.umd_namespaces_by_file.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_merged_interfaces() -> Result<(), Error> {
+        test_exported_type!(
+            r#"export interface Merged {
+                a: number;
+                f(): void;
+            }
+            export interface Merged extends Base {
+                b: string;
+                f(x: number): void;
+            }"#,
+            "Merged",
+            TypeInfo::Interface(iface),
+            {
+                assert_eq!(iface.fields.len(), 3);
+                assert!(iface.fields.contains_key("a"));
+                assert!(iface.fields.contains_key("b"));
+                assert_eq!(iface.extends.len(), 1);
+
+                if let TypeInfo::FuncGroup(fg) = &iface.fields["f"] {
+                    assert_eq!(fg.overloads.len(), 2);
+                } else {
+                    assert!(false);
+                }
+            }
+        )
+    }
+
+    #[test]
+    fn test_module_augmentation() -> Result<(), Error> {
+        let test_path: &Path = Path::new("/test.d.ts");
+        let lib_path: &Path = Path::new("/node_modules/lib.d.ts");
+        let mut fs: MemFs = Default::default();
+        fs.set_cwd(Path::new("/"));
+        fs.add_dir_at(Path::new("/node_modules"));
+        fs.add_file_at(
+            lib_path,
+            r#"export interface Options {
+                base: number;
+            }"#
+            .to_string(),
+        );
+        fs.add_file_at(
+            test_path,
+            r#"import { Options } from "lib";
+            declare module "lib" {
+                interface Options {
+                    extra: string;
+                }
+            }"#
+            .to_string(),
+        );
+
+        let mut tbnbf = TsTypes::parse(Arc::new(fs) as ArcFs, &test_path.to_string_lossy())?;
+        let mut types = tbnbf.remove(lib_path).unwrap();
+        let options = types
+            .remove(&TypeIdent::Name("Options".to_string()))
+            .unwrap();
+        assert!(options.is_exported);
+
+        if let TypeInfo::Interface(iface) = options.info {
+            assert_eq!(iface.fields.len(), 2);
+            assert!(iface.fields.contains_key("base"));
+            assert!(iface.fields.contains_key("extra"));
+        } else {
+            assert!(false);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_module_augmentation_of_reexport() -> Result<(), Error> {
+        let test_path: &Path = Path::new("/test.d.ts");
+        let lib_path: &Path = Path::new("/node_modules/lib.d.ts");
+        let options_path: &Path = Path::new("/node_modules/options.d.ts");
+        let mut fs: MemFs = Default::default();
+        fs.set_cwd(Path::new("/"));
+        fs.add_dir_at(Path::new("/node_modules"));
+        fs.add_file_at(
+            options_path,
+            r#"export interface Options {
+                base: number;
+            }"#
+            .to_string(),
+        );
+        fs.add_file_at(
+            lib_path,
+            r#"export { Options } from "./options";"#.to_string(),
+        );
+        fs.add_file_at(
+            test_path,
+            r#"import { Options } from "lib";
+            declare module "lib" {
+                interface Options {
+                    extra: string;
+                }
+            }"#
+            .to_string(),
+        );
+
+        let mut tbnbf = TsTypes::parse(Arc::new(fs) as ArcFs, &test_path.to_string_lossy())?;
+
+        let lib_types = tbnbf.remove(lib_path).unwrap();
+        assert_eq!(
+            lib_types[&TypeIdent::Name("Options".to_string())].info,
+            TypeInfo::NamespaceImport(NamespaceImport::Named {
+                src: options_path.to_path_buf(),
+                name: "Options".to_string(),
+            })
+        );
+
+        let mut options_types = tbnbf.remove(options_path).unwrap();
+        let options = options_types
+            .remove(&TypeIdent::Name("Options".to_string()))
+            .unwrap();
+        assert_eq!(options.name.file, options_path);
+
+        if let TypeInfo::Interface(iface) = options.info {
+            assert_eq!(iface.fields.len(), 2);
+            assert!(iface.fields.contains_key("base"));
+            assert!(iface.fields.contains_key("extra"));
+        } else {
+            assert!(false);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reference_directives() -> Result<(), Error> {
+        let test_path: &Path = Path::new("/test.d.ts");
+        let mut fs: MemFs = Default::default();
+        fs.set_cwd(Path::new("/"));
+        fs.add_dir_at(Path::new("/node_modules"));
+        fs.add_file_at(
+            Path::new("/globals.d.ts"),
+            r#"interface Foo {
+                a: number;
+            }"#
+            .to_string(),
+        );
+        fs.add_file_at(
+            Path::new("/node_modules/@types/node.d.ts"),
+            r#"interface Buffer {
+                length: number;
+            }
+            declare namespace NodeJS {
+                interface Timeout {
+                    ref(): Timeout;
+                }
+            }"#
+            .to_string(),
+        );
+        fs.add_file_at(
+            test_path,
+            r#"/// <reference path="globals.d.ts" />
+            /// <reference types="node" />
+            export declare function f(foo: Foo): Buffer;
+            export declare function setTimeout(cb: () => void): NodeJS.Timeout;"#
+                .to_string(),
+        );
+
+        let mut tbnbf = TsTypes::parse(Arc::new(fs) as ArcFs, &test_path.to_string_lossy())?;
+        let types = tbnbf.remove(test_path).unwrap();
+
+        assert_eq!(
+            types[&TypeIdent::Name("Foo".to_string())].info,
+            TypeInfo::NamespaceImport(NamespaceImport::Named {
+                src: PathBuf::from("/globals.d.ts"),
+                name: "Foo".to_string(),
+            })
+        );
+        assert_eq!(
+            types[&TypeIdent::Name("Buffer".to_string())].info,
+            TypeInfo::NamespaceImport(NamespaceImport::Named {
+                src: PathBuf::from("/node_modules/@types/node.d.ts"),
+                name: "Buffer".to_string(),
+            })
+        );
+        assert_eq!(
+            types[&TypeIdent::Name("NodeJS".to_string())].info,
+            TypeInfo::NamespaceImport(NamespaceImport::Namespace {
+                src: PathBuf::from("/node_modules/@types/node.d.ts"),
+                name_parts: vec!["NodeJS".to_string()],
+            })
+        );
+        assert!(tbnbf.contains_key(Path::new("/globals.d.ts")));
+
+        assert_eq!(
+            ReferenceDirective::parse("/ <reference path='./a.d.ts'/>"),
+            Some(ReferenceDirective::Path("./a.d.ts".to_string()))
+        );
+        assert_eq!(
+            ReferenceDirective::parse("/ <reference lib=\"dom\" />"),
+            None
+        );
+        assert_eq!(ReferenceDirective::parse(" <reference path=\"a\" />"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_type_param_constraints_and_defaults() -> Result<(), Error> {
+        test_exported_type!(
+            r#"export interface Wrapper<T extends Base, U = string> {
+                item: T;
+            }"#,
+            "Wrapper",
+            TypeInfo::Interface(iface),
+            {
+                assert_eq!(iface.type_params.len(), 2);
+                let (t, t_cfg) = &iface.type_params[0];
+                assert_eq!(t, "T");
+                assert!(matches!(t_cfg.constraint, Some(TypeInfo::Ref(_))));
+                assert_eq!(t_cfg.default_type_arg, None);
+
+                let (u, u_cfg) = &iface.type_params[1];
+                assert_eq!(u, "U");
+                assert_eq!(u_cfg.constraint, None);
+                assert_eq!(
+                    u_cfg.default_type_arg,
+                    Some(TypeInfo::PrimitiveString(PrimitiveString()))
+                );
+            }
+        )
+    }
+
+    #[test]
+    fn test_literal_union() -> Result<(), Error> {
+        test_exported_type!(
+            r#"export type Align = "left" | "right";"#,
+            "Align",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                assert_eq!(
+                    target.as_ref(),
+                    &TypeInfo::Union(Union {
+                        types: vec![
+                            TypeInfo::LitString(LitString {
+                                s: "left".to_string()
+                            }),
+                            TypeInfo::LitString(LitString {
+                                s: "right".to_string()
+                            }),
+                        ]
+                    })
+                );
+            }
+        )
+    }
+
+    #[test]
+    fn test_tuple_elements() -> Result<(), Error> {
+        test_exported_type!(
+            r#"export type Pair = [a: string, b?: number, ...rest: boolean[]];"#,
+            "Pair",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                assert_eq!(
+                    target.as_ref(),
+                    &TypeInfo::Tuple(Tuple {
+                        types: vec![
+                            TypeInfo::PrimitiveString(PrimitiveString()),
+                            TypeInfo::Optional {
+                                item_type: Box::new(TypeInfo::PrimitiveNumber(PrimitiveNumber())),
+                            },
+                            TypeInfo::Array {
+                                item_type: Box::new(TypeInfo::PrimitiveBoolean(PrimitiveBoolean())),
+                                readonly: false,
+                            },
+                        ],
+                        labels: vec!["a".to_string(), "b".to_string(), "rest".to_string()],
+                        has_rest: true,
+                    })
+                );
+            }
+        )?;
+
+        test_exported_type!(
+            r#"export type Unlabeled = [string, number?, ...number[]];"#,
+            "Unlabeled",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                assert_eq!(
+                    target.as_ref(),
+                    &TypeInfo::Tuple(Tuple {
+                        types: vec![
+                            TypeInfo::PrimitiveString(PrimitiveString()),
+                            TypeInfo::Optional {
+                                item_type: Box::new(TypeInfo::PrimitiveNumber(PrimitiveNumber())),
+                            },
+                            TypeInfo::Array {
+                                item_type: Box::new(TypeInfo::PrimitiveNumber(PrimitiveNumber())),
+                                readonly: false,
+                            },
+                        ],
+                        labels: vec![],
+                        has_rest: true,
+                    })
+                );
+            }
+        )?;
+
+        test_exported_type!(
+            r#"export type LeadingRest = [...string[], number];"#,
+            "LeadingRest",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                assert_eq!(
+                    target.as_ref(),
+                    &TypeInfo::Array {
+                        item_type: Box::new(TypeInfo::Union(Union {
+                            types: vec![
+                                TypeInfo::PrimitiveString(PrimitiveString()),
+                                TypeInfo::PrimitiveNumber(PrimitiveNumber()),
+                            ],
+                        })),
+                        readonly: false,
+                    }
+                );
+            }
+        )?;
+
+        test_exported_type!(
+            r#"export type GenericRest<T extends unknown[]> = [string, ...T];"#,
+            "GenericRest",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                assert_eq!(
+                    target.as_ref(),
+                    &TypeInfo::Array {
+                        item_type: Box::new(TypeInfo::PrimitiveAny(PrimitiveAny())),
+                        readonly: false,
+                    }
+                );
+            }
+        )?;
+
+        test_exported_type!(
+            r#"type Numbers = number[];
+            export type AliasedRest = [a: string, ...rest: Numbers];"#,
+            "AliasedRest",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                assert_eq!(
+                    target.as_ref(),
+                    &TypeInfo::Array {
+                        item_type: Box::new(TypeInfo::PrimitiveAny(PrimitiveAny())),
+                        readonly: false,
+                    }
+                );
+            }
+        )
+    }
+
+    #[test]
+    fn test_readonly_members() -> Result<(), Error> {
+        test_exported_type!(
+            r#"export interface Point {
+                readonly x: number;
+                y: number;
+                get z(): number;
+            }"#,
+            "Point",
+            TypeInfo::Interface(iface),
+            {
+                assert_eq!(
+                    iface.readonly_fields,
+                    vec!["x".to_string(), "z".to_string()].into_iter().collect()
+                );
+            }
+        )?;
+
+        test_exported_type!(
+            r#"export declare class Counter {
+                readonly count: number;
+                get total(): number;
+                get label(): string;
+                set label(l: string);
+            }"#,
+            "Counter",
+            TypeInfo::Class(c),
+            {
+                assert!(matches!(
+                    c.members.get(&MemberKey::Instance("count".to_string())),
+                    Some(Member::Property(Property { readonly: true, .. }))
+                ));
+                assert!(matches!(
+                    c.members.get(&MemberKey::Instance("total".to_string())),
+                    Some(Member::Property(Property { readonly: true, .. }))
+                ));
+                assert!(matches!(
+                    c.members.get(&MemberKey::Instance("label".to_string())),
+                    Some(Member::Property(Property {
+                        readonly: false,
+                        ..
+                    }))
+                ));
+            }
+        )
+    }
+
+    #[test]
+    fn test_readonly_arrays() -> Result<(), Error> {
+        test_exported_type!(
+            r#"export type Names = readonly string[];"#,
+            "Names",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                assert_eq!(
+                    target.as_ref(),
+                    &TypeInfo::Array {
+                        item_type: Box::new(TypeInfo::PrimitiveString(PrimitiveString())),
+                        readonly: true,
+                    }
+                );
+            }
+        )?;
+
+        test_exported_type!(
+            r#"export type Nums = ReadonlyArray<number>;"#,
+            "Nums",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                assert!(matches!(
+                    target.as_ref(),
+                    TypeInfo::Array { readonly: true, .. }
+                ));
+            }
+        )?;
+
+        test_exported_type!(
+            r#"interface Point {
+                x: number;
+            }
+            export type FrozenPoint = Readonly<Point>;"#,
+            "FrozenPoint",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                if let TypeInfo::Interface(iface) = target.as_ref() {
+                    assert!(iface.readonly_fields.contains("x"));
+                } else {
+                    assert!(false);
+                }
+            }
+        )?;
+
+        test_exported_type!(
+            r#"interface Named {
+                name: string;
+            }
+            interface Point extends Named {
+                x: number;
+            }
+            export type FrozenPoint = Readonly<Point>;"#,
+            "FrozenPoint",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                if let TypeInfo::Interface(iface) = target.as_ref() {
+                    assert!(iface.readonly_fields.contains("x"));
+                    assert!(iface.readonly_fields.contains("name"));
+                    assert!(iface.extends.is_empty());
+                } else {
+                    assert!(false);
+                }
+            }
+        )?;
+
+        test_exported_type!(
+            r#"export type FrozenPoint = Readonly<{
+                x: number;
+            }>;"#,
+            "FrozenPoint",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                if let TypeInfo::Interface(iface) = target.as_ref() {
+                    assert!(iface.readonly_fields.contains("x"));
+                } else {
+                    assert!(false);
+                }
+            }
+        )
+    }
+
+    #[test]
+    fn test_iterable_members() -> Result<(), Error> {
+        test_exported_type!(
+            r#"export declare class Segments {
+                length: number;
+                [Symbol.iterator](): IterableIterator<string>;
+                [Symbol.toStringTag]: string;
+            }"#,
+            "Segments",
+            TypeInfo::Class(c),
+            {
+                assert_eq!(
+                    c.members
+                        .get(&MemberKey::Instance("[Symbol.iterator]".to_string())),
+                    Some(&Member::Iterator(TypeInfo::PrimitiveString(
+                        PrimitiveString()
+                    )))
+                );
+                assert!(c
+                    .members
+                    .get(&MemberKey::Instance("[Symbol.toStringTag]".to_string()))
+                    .is_none());
+                assert!(c
+                    .members
+                    .get(&MemberKey::Instance("length".to_string()))
+                    .is_some());
+            }
+        )?;
+
+        test_exported_type!(
+            r#"export interface Named {
+                name: string;
+                [Symbol.iterator](): Iterator<string>;
+            }"#,
+            "Named",
+            TypeInfo::Interface(iface),
+            {
+                assert_eq!(
+                    iface.fields.keys().collect::<Vec<_>>(),
+                    vec![&"name".to_string()]
+                );
+                assert_eq!(
+                    iface.iterator,
+                    Some(Box::new(TypeInfo::PrimitiveString(PrimitiveString())))
+                );
+                assert!(iface.async_iterator.is_none());
+            }
+        )
+    }
+
+    #[test]
+    fn test_async_iterables() -> Result<(), Error> {
+        test_exported_type!(
+            r#"export type Pages = AsyncIterableIterator<string>;"#,
+            "Pages",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                assert_eq!(
+                    target.as_ref(),
+                    &TypeInfo::BuiltinAsyncIterable(crate::ir::base::BuiltinAsyncIterable {
+                        item_type: Box::new(TypeInfo::PrimitiveString(PrimitiveString())),
+                    })
+                );
+            }
+        )?;
+
+        test_exported_type!(
+            r#"export declare class Feed {
+                [Symbol.asyncIterator](): AsyncIterableIterator<number>;
+            }"#,
+            "Feed",
+            TypeInfo::Class(c),
+            {
+                assert_eq!(
+                    c.members
+                        .get(&MemberKey::Instance("[Symbol.asyncIterator]".to_string())),
+                    Some(&Member::AsyncIterator(TypeInfo::PrimitiveNumber(
+                        PrimitiveNumber()
+                    )))
+                );
+            }
+        )?;
+
+        test_exported_type!(
+            r#"export interface Feed {
+                [Symbol.asyncIterator](): AsyncIterableIterator<number>;
+            }"#,
+            "Feed",
+            TypeInfo::Interface(iface),
+            {
+                assert!(iface.fields.is_empty());
+                assert_eq!(
+                    iface.async_iterator,
+                    Some(Box::new(TypeInfo::PrimitiveNumber(PrimitiveNumber())))
+                );
+            }
+        )
+    }
+
+    #[test]
+    fn test_type_predicates() -> Result<(), Error> {
+        test_exported_type!(
+            r#"export interface Node {
+                id: string;
+            }
+            export function isNode(x: any): x is Node;"#,
+            "isNode",
+            TypeInfo::FuncGroup(FuncGroup { overloads }),
+            {
+                assert_eq!(overloads.len(), 1);
+                assert!(matches!(
+                    overloads[0].return_type.as_ref(),
+                    TypeInfo::TypePredicate(TypePredicate {
+                        param_name,
+                        asserted_type,
+                    }) if param_name == "x"
+                        && matches!(asserted_type.as_ref(), TypeInfo::Ref(_))
+                ));
+            }
+        )?;
+
+        test_exported_type!(
+            r#"export function assertIsString(x: unknown): asserts x is string;"#,
+            "assertIsString",
+            TypeInfo::FuncGroup(FuncGroup { overloads }),
+            {
+                assert_eq!(
+                    overloads[0].return_type.as_ref(),
+                    &TypeInfo::PrimitiveVoid(PrimitiveVoid())
+                );
             }
         )
     }
 
     #[test]
-    fn test_interface_indexer() -> Result<(), Error> {
+    fn test_template_literal_types() -> Result<(), Error> {
         test_exported_type!(
-            r#"export interface A {
-                [n: string]: number;
-            }"#,
-            "A",
-            TypeInfo::Interface(i),
+            r#"
+                export type Events = "click" | "focus";
+                export type Handlers = `on${Capitalize<Events>}`;
+            "#,
+            "Handlers",
+            TypeInfo::Alias(Alias { target, .. }),
             {
-                assert!(i.fields.is_empty());
-                assert!(i.indexer.is_some());
-                let indexer = i.indexer.as_ref().unwrap();
                 assert_eq!(
-                    *indexer.type_info,
-                    TypeInfo::PrimitiveNumber(PrimitiveNumber())
+                    target.as_ref(),
+                    &TypeInfo::Union(Union {
+                        types: vec![
+                            TypeInfo::LitString(LitString {
+                                s: "onClick".to_string()
+                            }),
+                            TypeInfo::LitString(LitString {
+                                s: "onFocus".to_string()
+                            }),
+                        ]
+                    })
+                );
+            }
+        )?;
+
+        test_exported_type!(
+            r#"
+                export type Color = `#${string}`;
+            "#,
+            "Color",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                assert_eq!(
+                    target.as_ref(),
+                    &TypeInfo::TemplateLiteral(TemplateLiteral {
+                        quasis: vec!["#".to_string(), "".to_string()],
+                        types: vec![TypeInfo::PrimitiveString(PrimitiveString())],
+                    })
                 );
             }
         )
     }
 
     #[test]
-    fn test_interface_constructor() -> Result<(), Error> {
+    fn test_bigint_literal_types() -> Result<(), Error> {
         test_exported_type!(
-            r#"export interface A {
-                new (n: number): A;
-            }"#,
-            "A",
-            TypeInfo::Interface(i),
+            r#"
+                export type Big = 9007199254740993n;
+            "#,
+            "Big",
+            TypeInfo::Alias(Alias { target, .. }),
             {
-                assert!(i.fields.is_empty());
-                assert!(i.indexer.is_none());
-                assert!(i.constructor.is_some());
-                let ctor = i.constructor.as_ref().unwrap();
-                assert_eq!(ctor.params.len(), 1);
-                let param = ctor.params.first().unwrap();
                 assert_eq!(
-                    *param,
-                    Param {
-                        name: "n".to_string(),
-                        type_info: TypeInfo::PrimitiveNumber(PrimitiveNumber()),
-                        is_variadic: false,
-                    }
+                    target.as_ref(),
+                    &TypeInfo::LitBigInt(LitBigInt {
+                        n: "9007199254740993".to_string()
+                    })
+                );
+            }
+        )?;
+
+        test_exported_type!(
+            r#"
+                export type Version = `v${1n | 2n}`;
+            "#,
+            "Version",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                assert_eq!(
+                    target.as_ref(),
+                    &TypeInfo::Union(Union {
+                        types: vec![
+                            TypeInfo::LitString(LitString {
+                                s: "v1".to_string()
+                            }),
+                            TypeInfo::LitString(LitString {
+                                s: "v2".to_string()
+                            }),
+                        ]
+                    })
                 );
             }
         )
@@ -2474,15 +4785,15 @@ mod test {
                 assert_eq!(e.members.len(), 3);
                 assert!(e.members.contains(&EnumMember {
                     id: "A".to_string(),
-                    value: None,
+                    value: Some(EnumValue::Num(0.0)),
                 }));
                 assert!(e.members.contains(&EnumMember {
                     id: "B".to_string(),
-                    value: None,
+                    value: Some(EnumValue::Num(1.0)),
                 }));
                 assert!(e.members.contains(&EnumMember {
                     id: "C".to_string(),
-                    value: None,
+                    value: Some(EnumValue::Num(2.0)),
                 }));
             }
         )
@@ -2549,6 +4860,67 @@ mod test {
         )
     }
 
+    #[test]
+    fn test_computed_enum() -> Result<(), Error> {
+        test_exported_type!(
+            r#"export const enum Flags {
+                None = 0,
+                A = 1 << 0,
+                B = 1 << 1,
+                AB = A | B,
+                Neg = -(AB + 1),
+                Inv = ~A,
+                Str = "a" + "b",
+            }"#,
+            "Flags",
+            TypeInfo::Enum(e),
+            {
+                let values: Vec<_> = e.members.into_iter().map(|m| m.value).collect();
+                assert_eq!(
+                    values,
+                    vec![
+                        Some(EnumValue::Num(0.0)),
+                        Some(EnumValue::Num(1.0)),
+                        Some(EnumValue::Num(2.0)),
+                        Some(EnumValue::Num(3.0)),
+                        Some(EnumValue::Num(-4.0)),
+                        Some(EnumValue::Num(-2.0)),
+                        Some(EnumValue::Str("ab".to_string())),
+                    ]
+                );
+            }
+        )?;
+
+        test_exported_type!(
+            r#"export enum Flags {
+                A = 1,
+                B = 2,
+                AB = Flags.A | Flags.B,
+                ABC = Flags["AB"] | 4,
+                Computed = "abc".length,
+                AfterComputed,
+                Last = 8,
+            }"#,
+            "Flags",
+            TypeInfo::Enum(e),
+            {
+                let values: Vec<_> = e.members.into_iter().map(|m| m.value).collect();
+                assert_eq!(
+                    values,
+                    vec![
+                        Some(EnumValue::Num(1.0)),
+                        Some(EnumValue::Num(2.0)),
+                        Some(EnumValue::Num(3.0)),
+                        Some(EnumValue::Num(7.0)),
+                        None,
+                        None,
+                        Some(EnumValue::Num(8.0)),
+                    ]
+                );
+            }
+        )
+    }
+
     #[test]
     fn test_this_type() -> Result<(), Error> {
         test_exported_type!(
@@ -2562,8 +4934,7 @@ mod test {
                 let n = i.fields.get("thisThing");
                 assert!(n.is_some());
                 let n = n.unwrap();
-                // TODO: this is a very bad solution
-                assert_eq!(*n, TypeInfo::PrimitiveAny(PrimitiveAny()));
+                assert_eq!(*n, TypeInfo::PolymorphicThis(PolymorphicThis()));
             }
         )
     }
@@ -2896,7 +5267,30 @@ mod test {
             "A",
             TypeInfo::Alias(Alias { target, .. }),
             {
-                assert!(matches!(target.as_ref(), TypeInfo::Union(_)));
+                assert_eq!(
+                    target.as_ref(),
+                    &TypeInfo::LitString(LitString { s: "a".to_string() })
+                );
+            }
+        )?;
+
+        test_exported_type!(
+            r#"
+                export type Keys = "a" | "b" | "c";
+                export type A = Exclude<Keys, "a" | string[]>;
+            "#,
+            "A",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                assert_eq!(
+                    target.as_ref(),
+                    &TypeInfo::Union(Union {
+                        types: vec![
+                            TypeInfo::LitString(LitString { s: "b".to_string() }),
+                            TypeInfo::LitString(LitString { s: "c".to_string() }),
+                        ]
+                    })
+                );
             }
         )
     }
@@ -2910,25 +5304,199 @@ mod test {
             "A",
             TypeInfo::Alias(Alias { target, .. }),
             {
-                assert!(matches!(target.as_ref(), TypeInfo::Union(_)));
+                assert_eq!(
+                    target.as_ref(),
+                    &TypeInfo::LitString(LitString { s: "b".to_string() })
+                );
+            }
+        )?;
+
+        test_exported_type!(
+            r#"
+                export type A = Extract<string | number | boolean, string | number>;
+            "#,
+            "A",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                assert_eq!(
+                    target.as_ref(),
+                    &TypeInfo::Union(Union {
+                        types: vec![
+                            TypeInfo::PrimitiveString(PrimitiveString()),
+                            TypeInfo::PrimitiveNumber(PrimitiveNumber()),
+                        ]
+                    })
+                );
+            }
+        )
+    }
+
+    #[test]
+    fn test_utility_type_omit() -> Result<(), Error> {
+        test_exported_type!(
+            r#"
+                export interface Base {
+                    a: string;
+                    opt?: string;
+                }
+                export interface Props extends Base {
+                    children: string;
+                    b: string;
+                }
+                export type A = Omit<Props, "children" | "opt">;
+            "#,
+            "A",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                if let TypeInfo::Interface(iface) = target.as_ref() {
+                    assert_eq!(iface.fields.len(), 2);
+                    assert!(iface.fields.contains_key("a"));
+                    assert!(iface.fields.contains_key("b"));
+                } else {
+                    assert!(false);
+                }
+            }
+        )
+    }
+
+    #[test]
+    fn test_utility_type_awaited() -> Result<(), Error> {
+        test_exported_type!(
+            r#"
+                export type A = Awaited<Promise<Promise<string>> | number>;
+            "#,
+            "A",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                assert_eq!(
+                    target.as_ref(),
+                    &TypeInfo::Union(Union {
+                        types: vec![
+                            TypeInfo::PrimitiveString(PrimitiveString()),
+                            TypeInfo::PrimitiveNumber(PrimitiveNumber()),
+                        ]
+                    })
+                );
+            }
+        )
+    }
+
+    #[test]
+    fn test_utility_type_this_type() -> Result<(), Error> {
+        test_exported_type!(
+            r#"
+                export interface Methods {
+                    greet(): string;
+                }
+                export type A = ThisType<Methods>;
+            "#,
+            "A",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                if let TypeInfo::Interface(iface) = target.as_ref() {
+                    assert!(iface.fields.is_empty());
+                } else {
+                    assert!(false);
+                }
+            }
+        )
+    }
+
+    #[test]
+    fn test_utility_type_nonnullable() -> Result<(), Error> {
+        test_exported_type!(
+            r#"
+                export type A = NonNullable<"a" | "b" | null | undefined>;
+            "#,
+            "A",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                if let TypeInfo::Union(Union { types }) = target.as_ref() {
+                    assert_eq!(types.len(), 2);
+                } else {
+                    assert!(false);
+                }
+            }
+        )
+    }
+
+    #[test]
+    fn test_intrinsic_string_manipulation() -> Result<(), Error> {
+        test_exported_type!(
+            r#"
+                export type Events = "click" | "keyUp";
+                export type A = Capitalize<Events>;
+            "#,
+            "A",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                assert_eq!(
+                    target.as_ref(),
+                    &TypeInfo::Union(Union {
+                        types: vec![
+                            TypeInfo::LitString(LitString {
+                                s: "Click".to_string()
+                            }),
+                            TypeInfo::LitString(LitString {
+                                s: "KeyUp".to_string()
+                            }),
+                        ]
+                    })
+                );
+            }
+        )?;
+
+        test_exported_type!(
+            r#"
+                export type A = Uppercase<"abc"> | Lowercase<"ABC"> | Uncapitalize<"XyZ">;
+            "#,
+            "A",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                assert_eq!(
+                    target.as_ref(),
+                    &TypeInfo::Union(Union {
+                        types: vec![
+                            TypeInfo::LitString(LitString {
+                                s: "ABC".to_string()
+                            }),
+                            TypeInfo::LitString(LitString {
+                                s: "abc".to_string()
+                            }),
+                            TypeInfo::LitString(LitString {
+                                s: "xyZ".to_string()
+                            }),
+                        ]
+                    })
+                );
+            }
+        )?;
+
+        test_exported_type!(
+            r#"
+                export type A = Uppercase<string>;
+            "#,
+            "A",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                assert_eq!(
+                    target.as_ref(),
+                    &TypeInfo::PrimitiveString(PrimitiveString())
+                );
             }
-        )
-    }
+        )?;
 
-    #[test]
-    fn test_utility_type_nonnullable() -> Result<(), Error> {
         test_exported_type!(
             r#"
-                export type A = NonNullable<"a" | "b" | null | undefined>;
+                export type Shout<S extends string> = intrinsic;
             "#,
-            "A",
+            "Shout",
             TypeInfo::Alias(Alias { target, .. }),
             {
-                if let TypeInfo::Union(Union { types }) = target.as_ref() {
-                    assert_eq!(types.len(), 2);
-                } else {
-                    assert!(false);
-                }
+                assert_eq!(
+                    target.as_ref(),
+                    &TypeInfo::PrimitiveString(PrimitiveString())
+                );
             }
         )
     }
@@ -3001,7 +5569,7 @@ mod test {
             "A",
             TypeInfo::Alias(Alias { target, .. }),
             {
-                if let TypeInfo::Tuple(Tuple { types }) = target.as_ref() {
+                if let TypeInfo::Tuple(Tuple { types, .. }) = target.as_ref() {
                     assert_eq!(types.len(), 2);
                 } else {
                     assert!(false);
@@ -3020,7 +5588,7 @@ mod test {
             "A",
             TypeInfo::Alias(Alias { target, .. }),
             {
-                if let TypeInfo::Tuple(Tuple { types }) = target.as_ref() {
+                if let TypeInfo::Tuple(Tuple { types, .. }) = target.as_ref() {
                     assert_eq!(types.len(), 2);
                 } else {
                     assert!(false);
@@ -3041,7 +5609,7 @@ mod test {
             "A",
             TypeInfo::Alias(Alias { target, .. }),
             {
-                if let TypeInfo::Tuple(Tuple { types }) = target.as_ref() {
+                if let TypeInfo::Tuple(Tuple { types, .. }) = target.as_ref() {
                     assert_eq!(types.len(), 1);
                 } else {
                     assert!(false);
@@ -3062,7 +5630,7 @@ mod test {
             "A",
             TypeInfo::Alias(Alias { target, .. }),
             {
-                if let TypeInfo::Tuple(Tuple { types }) = target.as_ref() {
+                if let TypeInfo::Tuple(Tuple { types, .. }) = target.as_ref() {
                     assert_eq!(types.len(), 1);
                 } else {
                     assert!(false);
@@ -3170,7 +5738,7 @@ mod test {
             "A",
             TypeInfo::Alias(Alias { target, .. }),
             {
-                if let TypeInfo::Array { item_type } = target.as_ref() {
+                if let TypeInfo::Array { item_type, .. } = target.as_ref() {
                     if let TypeInfo::Union(Union { types }) = item_type.as_ref() {
                         assert_eq!(types.len(), 2);
                     } else {
@@ -3208,4 +5776,344 @@ mod test {
             }
         )
     }
+
+    #[test]
+    fn test_conditional_type_true_branch() -> Result<(), Error> {
+        test_exported_type!(
+            r#"
+                export type A = "a" extends string ? number : boolean;
+            "#,
+            "A",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                assert!(matches!(target.as_ref(), TypeInfo::PrimitiveNumber(_)));
+            }
+        )
+    }
+
+    #[test]
+    fn test_conditional_type_false_branch() -> Result<(), Error> {
+        test_exported_type!(
+            r#"
+                export interface Base {
+                    a: string;
+                }
+                export interface Other {
+                    b: string;
+                }
+                export type A = Other extends Base ? number : boolean;
+            "#,
+            "A",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                assert!(matches!(target.as_ref(), TypeInfo::PrimitiveBoolean(_)));
+            }
+        )
+    }
+
+    #[test]
+    fn test_conditional_type_undecidable() -> Result<(), Error> {
+        test_exported_type!(
+            r#"
+                export type A<T> = T extends string ? number : boolean;
+            "#,
+            "A",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                if let TypeInfo::Union(Union { types }) = target.as_ref() {
+                    assert_eq!(types.len(), 2);
+                    assert!(matches!(types[0], TypeInfo::PrimitiveNumber(_)));
+                    assert!(matches!(types[1], TypeInfo::PrimitiveBoolean(_)));
+                } else {
+                    assert!(false);
+                }
+            }
+        )
+    }
+
+    #[test]
+    fn test_conditional_type_generic_alias() -> Result<(), Error> {
+        test_exported_type!(
+            r#"
+                type IsString<T> = T extends string ? "yes" : "no";
+                export type A = IsString<"a">;
+            "#,
+            "A",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                assert_eq!(
+                    target.as_ref(),
+                    &TypeInfo::LitString(LitString {
+                        s: "yes".to_string()
+                    })
+                );
+            }
+        )?;
+
+        test_exported_type!(
+            r#"
+                type IsString<T> = T extends string ? "yes" : "no";
+                export interface I {
+                    n: IsString<number>;
+                }
+            "#,
+            "I",
+            TypeInfo::Interface(iface),
+            {
+                assert_eq!(
+                    iface.fields.get("n"),
+                    Some(&TypeInfo::LitString(LitString {
+                        s: "no".to_string()
+                    }))
+                );
+            }
+        )
+    }
+
+    #[test]
+    fn test_conditional_type_duplicate_branches() -> Result<(), Error> {
+        test_exported_type!(
+            r#"
+                export type A<T> = T extends string ? number | string : string | boolean;
+            "#,
+            "A",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                assert_eq!(
+                    target.as_ref(),
+                    &TypeInfo::Union(Union {
+                        types: vec![
+                            TypeInfo::PrimitiveNumber(PrimitiveNumber()),
+                            TypeInfo::PrimitiveString(PrimitiveString()),
+                            TypeInfo::PrimitiveBoolean(PrimitiveBoolean()),
+                        ]
+                    })
+                );
+            }
+        )
+    }
+
+    #[test]
+    fn test_mapped_type_keyof() -> Result<(), Error> {
+        test_exported_type!(
+            r#"
+                export interface Base {
+                    a: string;
+                    opt?: string;
+                }
+                export type A = { [K in keyof Base]: boolean };
+            "#,
+            "A",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                if let TypeInfo::Interface(iface) = target.as_ref() {
+                    assert_eq!(iface.fields.len(), 2);
+                    assert!(matches!(
+                        iface.fields.get("a"),
+                        Some(TypeInfo::PrimitiveBoolean(_))
+                    ));
+                    assert!(matches!(
+                        iface.fields.get("opt"),
+                        Some(TypeInfo::Optional { .. })
+                    ));
+                } else {
+                    assert!(false);
+                }
+            }
+        )
+    }
+
+    #[test]
+    fn test_mapped_type_function_template() -> Result<(), Error> {
+        test_exported_type!(
+            r#"
+                export interface Base {
+                    a: string;
+                    b: number;
+                }
+                export type Getters = { [K in keyof Base]: () => Base[K] };
+            "#,
+            "Getters",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                if let TypeInfo::Interface(iface) = target.as_ref() {
+                    assert_eq!(iface.fields.len(), 2);
+                    if let Some(TypeInfo::FuncGroup(FuncGroup { overloads })) =
+                        iface.fields.get("a")
+                    {
+                        assert!(matches!(
+                            overloads[0].return_type.as_ref(),
+                            TypeInfo::PrimitiveString(_)
+                        ));
+                    } else {
+                        assert!(false);
+                    }
+                } else {
+                    assert!(false);
+                }
+            }
+        )?;
+
+        test_exported_type!(
+            r#"
+                export interface Base {
+                    a: string;
+                }
+                export type Setters = { [K in keyof Base]: { set(value: Base[K]): void } };
+            "#,
+            "Setters",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                if let TypeInfo::Interface(iface) = target.as_ref() {
+                    if let Some(TypeInfo::Interface(setter)) = iface.fields.get("a") {
+                        if let Some(TypeInfo::FuncGroup(FuncGroup { overloads })) =
+                            setter.fields.get("set")
+                        {
+                            assert!(matches!(
+                                overloads[0].params[0].type_info,
+                                TypeInfo::PrimitiveString(_)
+                            ));
+                        } else {
+                            assert!(false);
+                        }
+                    } else {
+                        assert!(false);
+                    }
+                } else {
+                    assert!(false);
+                }
+            }
+        )
+    }
+
+    #[test]
+    fn test_mapped_type_literal_keys_with_modifiers() -> Result<(), Error> {
+        test_exported_type!(
+            r#"
+                export type A = { readonly [K in "a" | "b"]?: number };
+            "#,
+            "A",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                if let TypeInfo::Interface(iface) = target.as_ref() {
+                    assert_eq!(iface.fields.len(), 2);
+                    assert!(iface
+                        .fields
+                        .values()
+                        .all(|f| matches!(f, TypeInfo::Optional { .. })));
+                } else {
+                    assert!(false);
+                }
+            }
+        )
+    }
+
+    #[test]
+    fn test_mapped_type_open_keys() -> Result<(), Error> {
+        test_exported_type!(
+            r#"
+                export type A = { [K in string]: number };
+            "#,
+            "A",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                if let TypeInfo::Mapped { value_type } = target.as_ref() {
+                    assert!(matches!(value_type.as_ref(), TypeInfo::PrimitiveNumber(_)));
+                } else {
+                    assert!(false);
+                }
+            }
+        )
+    }
+
+    #[test]
+    fn test_indexed_access_property() -> Result<(), Error> {
+        test_exported_type!(
+            r#"
+                export interface Config {
+                    plugins: string[];
+                }
+                export type A = Config["plugins"][number];
+            "#,
+            "A",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                assert!(matches!(target.as_ref(), TypeInfo::PrimitiveString(_)));
+            }
+        )
+    }
+
+    #[test]
+    fn test_indexed_access_keyof() -> Result<(), Error> {
+        test_exported_type!(
+            r#"
+                export interface Config {
+                    name: string;
+                    alias: string;
+                    port: number;
+                }
+                export type A = Config[keyof Config];
+            "#,
+            "A",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                if let TypeInfo::Union(Union { types }) = target.as_ref() {
+                    assert_eq!(types.len(), 2);
+                    assert!(types.contains(&TypeInfo::PrimitiveString(PrimitiveString())));
+                    assert!(types.contains(&TypeInfo::PrimitiveNumber(PrimitiveNumber())));
+                } else {
+                    assert!(false);
+                }
+            }
+        )
+    }
+
+    #[test]
+    fn test_indexed_access_tuple() -> Result<(), Error> {
+        test_exported_type!(
+            r#"
+                export declare function f(a: string, b: number): void;
+                export type A = Parameters<typeof f>[1];
+            "#,
+            "A",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                assert!(matches!(target.as_ref(), TypeInfo::PrimitiveNumber(_)));
+            }
+        )
+    }
+
+    #[test]
+    fn test_keyof_interface() -> Result<(), Error> {
+        test_exported_type!(
+            r#"
+                export interface Options {
+                    b: number;
+                    a: string;
+                }
+                export type A = keyof Options;
+            "#,
+            "A",
+            TypeInfo::Alias(Alias { target, .. }),
+            {
+                if let TypeInfo::Enum(Enum { members }) = target.as_ref() {
+                    assert_eq!(
+                        members,
+                        &vec![
+                            EnumMember {
+                                id: "a".to_string(),
+                                value: Some(EnumValue::Str("a".to_string())),
+                            },
+                            EnumMember {
+                                id: "b".to_string(),
+                                value: Some(EnumValue::Str("b".to_string())),
+                            },
+                        ]
+                    );
+                } else {
+                    assert!(false);
+                }
+            }
+        )
+    }
 }
@@ -0,0 +1,240 @@
+//! Adapts javascript async iterables to rust streams
+
+use crate::{deserialize_as_jsvalue, from_jsvalue, serialize_as_jsvalue, Error};
+use futures_core::Stream;
+use js_sys::{Function, Promise, Reflect, Symbol};
+use serde::{de, ser};
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use wasm_bindgen::{
+    convert::{FromWasmAbi, IntoWasmAbi},
+    describe::WasmDescribe,
+    JsCast, JsValue,
+};
+use wasm_bindgen_futures::JsFuture;
+
+/// A [Stream] of the values produced by a javascript async iterable (or async
+/// iterator), each deserialized into a `T`.
+///
+/// Iteration starts the first time the stream is polled and ends after the
+/// iterator reports that it is done or fails.
+pub struct AsyncIterStream<T> {
+    iterable: JsValue,
+    iterator: Option<JsValue>,
+    next: Option<JsFuture>,
+    done: bool,
+    item: PhantomData<fn() -> T>,
+}
+
+impl<T> AsyncIterStream<T> {
+    /// Returns the underlying javascript async iterable
+    pub fn as_js_value(&self) -> &JsValue {
+        &self.iterable
+    }
+
+    /// Returns the async iterator, retrieving it from `[Symbol.asyncIterator]()`
+    /// on first use. Objects without `[Symbol.asyncIterator]` are assumed to be
+    /// async iterators themselves.
+    fn iterator(&mut self) -> Result<JsValue, JsValue> {
+        if let Some(iterator) = &self.iterator {
+            return Ok(iterator.clone());
+        }
+
+        let get_iterator = Reflect::get(&self.iterable, &Symbol::async_iterator())?;
+        let iterator = if get_iterator.is_function() {
+            get_iterator
+                .unchecked_into::<Function>()
+                .call0(&self.iterable)?
+        } else {
+            self.iterable.clone()
+        };
+
+        self.iterator = Some(iterator.clone());
+        Ok(iterator)
+    }
+
+    /// Calls `next()` on our iterator, returning a future for its result
+    fn next_result(&mut self) -> Result<JsFuture, JsValue> {
+        let iterator = self.iterator()?;
+        let next: Function = Reflect::get(&iterator, &JsValue::from_str("next"))?.dyn_into()?;
+        let result = next.call0(&iterator)?;
+
+        Ok(JsFuture::from(Promise::resolve(&result)))
+    }
+}
+
+impl<T> AsyncIterStream<T>
+where
+    T: de::DeserializeOwned,
+{
+    /// Converts an iterator result, `{ done, value }`, into its value, if any
+    fn value_of(result: JsValue) -> Result<Option<T>, JsValue> {
+        if Reflect::get(&result, &JsValue::from_str("done"))?.is_truthy() {
+            return Ok(None);
+        }
+
+        let value = Reflect::get(&result, &JsValue::from_str("value"))?;
+        from_jsvalue(&value)
+            .map(Some)
+            .map_err(Error::from)
+            .map_err(JsValue::from)
+    }
+}
+
+impl<T> Stream for AsyncIterStream<T>
+where
+    T: de::DeserializeOwned,
+{
+    type Item = Result<T, JsValue>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.done {
+            return Poll::Ready(None);
+        }
+
+        if this.next.is_none() {
+            match this.next_result() {
+                Ok(next) => this.next = Some(next),
+                Err(err) => {
+                    this.done = true;
+                    return Poll::Ready(Some(Err(err)));
+                }
+            }
+        }
+
+        let result = match this.next.as_mut().map(|next| Pin::new(next).poll(cx)) {
+            Some(Poll::Ready(result)) => result,
+            _ => return Poll::Pending,
+        };
+        this.next = None;
+
+        match result.and_then(Self::value_of) {
+            Ok(Some(value)) => Poll::Ready(Some(Ok(value))),
+            Ok(None) => {
+                this.done = true;
+                Poll::Ready(None)
+            }
+            Err(err) => {
+                this.done = true;
+                Poll::Ready(Some(Err(err)))
+            }
+        }
+    }
+}
+
+impl<T> Clone for AsyncIterStream<T> {
+    /// Clones the underlying iterable. The clone starts its own iteration.
+    fn clone(&self) -> Self {
+        Self::from(self.iterable.clone())
+    }
+}
+
+impl<T> From<JsValue> for AsyncIterStream<T> {
+    fn from(iterable: JsValue) -> Self {
+        AsyncIterStream {
+            iterable,
+            iterator: None,
+            next: None,
+            done: false,
+            item: PhantomData,
+        }
+    }
+}
+
+impl<T> From<AsyncIterStream<T>> for JsValue {
+    fn from(src: AsyncIterStream<T>) -> JsValue {
+        src.iterable
+    }
+}
+
+impl<T> AsRef<JsValue> for AsyncIterStream<T> {
+    fn as_ref(&self) -> &JsValue {
+        &self.iterable
+    }
+}
+
+impl<T> WasmDescribe for AsyncIterStream<T> {
+    fn describe() {
+        <JsValue as WasmDescribe>::describe()
+    }
+}
+
+impl<T> FromWasmAbi for AsyncIterStream<T> {
+    type Abi = <JsValue as FromWasmAbi>::Abi;
+
+    unsafe fn from_abi(js: Self::Abi) -> Self {
+        Self::from(<JsValue as FromWasmAbi>::from_abi(js))
+    }
+}
+
+impl<T> IntoWasmAbi for AsyncIterStream<T> {
+    type Abi = <JsValue as IntoWasmAbi>::Abi;
+
+    fn into_abi(self) -> Self::Abi {
+        self.iterable.into_abi()
+    }
+}
+
+impl<'a, T> IntoWasmAbi for &'a AsyncIterStream<T> {
+    type Abi = <&'a JsValue as IntoWasmAbi>::Abi;
+
+    fn into_abi(self) -> Self::Abi {
+        (&self.iterable).into_abi()
+    }
+}
+
+impl<T> ser::Serialize for AsyncIterStream<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serialize_as_jsvalue(serializer, &self.iterable)
+    }
+}
+
+impl<'de, T> de::Deserialize<'de> for AsyncIterStream<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let iterable: JsValue = deserialize_as_jsvalue(deserializer)?;
+        Ok(Self::from(iterable))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::task::{RawWaker, RawWakerVTable, Waker};
+
+    fn noop_waker() -> Waker {
+        fn clone(_: *const ()) -> RawWaker {
+            RawWaker::new(std::ptr::null(), &VTABLE)
+        }
+        fn noop(_: *const ()) {}
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+        unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) }
+    }
+
+    #[test]
+    fn test_finished_stream_stays_finished() {
+        let mut stream: AsyncIterStream<f64> = JsValue::undefined().into();
+        assert!(!stream.done);
+        stream.done = true;
+
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        for _ in 0..2 {
+            assert!(matches!(
+                Pin::new(&mut stream).poll_next(&mut cx),
+                Poll::Ready(None)
+            ));
+        }
+        assert!(stream.next.is_none());
+        assert!(stream.iterator.is_none());
+    }
+}
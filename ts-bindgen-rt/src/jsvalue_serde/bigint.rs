@@ -0,0 +1,36 @@
+//! Lossless conversions between javascript bigints and rust integers
+
+use std::fmt::Display;
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    /// `BigInt(s)`, parsing a bigint from its decimal representation
+    #[wasm_bindgen(js_name = BigInt)]
+    fn parse_bigint(s: &str) -> JsValue;
+
+    /// `String(v)`
+    #[wasm_bindgen(js_name = String)]
+    fn js_string(v: &JsValue) -> String;
+}
+
+/// Returns whether `v` is a javascript bigint
+pub fn is_bigint(v: &JsValue) -> bool {
+    v.js_typeof().as_string().as_deref() == Some("bigint")
+}
+
+/// Converts an integer into a javascript bigint
+pub fn to_bigint<T: Display>(n: T) -> JsValue {
+    parse_bigint(&n.to_string())
+}
+
+/// Converts a javascript bigint into an integer, returning None if `v` is not
+/// a bigint or is out of range for `T`
+pub fn from_bigint<T: FromStr>(v: &JsValue) -> Option<T> {
+    if is_bigint(v) {
+        js_string(v).parse().ok()
+    } else {
+        None
+    }
+}
@@ -1,3 +1,4 @@
+use crate::jsvalue_serde::bigint::{from_bigint, is_bigint};
 use crate::jsvalue_serde::error::{Error, ExpectValue, Result};
 use serde::{de, Deserialize};
 use std::borrow::{Borrow, Cow};
@@ -63,6 +64,25 @@ macro_rules! basic_deserialize {
     };
 }
 
+// deserializes bigints losslessly, falling back to (lossy) numbers
+macro_rules! bigint_deserialize {
+    ($name:ident, $visit:ident, $type:ident) => {
+        fn $name<V>(self, visitor: V) -> Result<V::Value>
+        where
+            V: de::Visitor<'de>,
+        {
+            let input: &JsValue = self.input.borrow();
+            let value = if is_bigint(input) {
+                from_bigint(input)
+            } else {
+                input.as_f64().map(|x| x as $type)
+            }
+            .expect_value(stringify!($type))?;
+            visitor.$visit(value)
+        }
+    };
+}
+
 macro_rules! error_deserialize {
     ($name:ident) => {
         fn $name<V>(self, _visitor: V) -> Result<V::Value>
@@ -82,12 +102,14 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
     basic_deserialize!(deserialize_i8, visit_i8, i8, as_f64 as i8);
     basic_deserialize!(deserialize_i16, visit_i16, i16, as_f64 as i16);
     basic_deserialize!(deserialize_i32, visit_i32, i32, as_f64 as i32);
-    basic_deserialize!(deserialize_i64, visit_i64, i64, as_f64 as i64);
+    bigint_deserialize!(deserialize_i64, visit_i64, i64);
+    bigint_deserialize!(deserialize_i128, visit_i128, i128);
 
     basic_deserialize!(deserialize_u8, visit_u8, u8, as_f64 as u8);
     basic_deserialize!(deserialize_u16, visit_u16, u16, as_f64 as u16);
     basic_deserialize!(deserialize_u32, visit_u32, u32, as_f64 as u32);
-    basic_deserialize!(deserialize_u64, visit_u64, u64, as_f64 as u64);
+    bigint_deserialize!(deserialize_u64, visit_u64, u64);
+    bigint_deserialize!(deserialize_u128, visit_u128, u128);
 
     basic_deserialize!(deserialize_f32, visit_f32, f32, as_f64 as f32);
     basic_deserialize!(deserialize_f64, visit_f64, f64, as_f64);
@@ -270,7 +292,8 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
             } else {
                 self.visit_jsvalue(visitor)
             }
-        } else if self.input.is_function() {
+        } else if self.input.is_function() || is_bigint(self.input.borrow()) {
+            // bigints may exceed any integer type so we leave them as JsValues
             self.visit_jsvalue(visitor)
         } else {
             Err(Error::InvalidType("unhandled any".to_string()))
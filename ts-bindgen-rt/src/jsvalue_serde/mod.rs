@@ -1,9 +1,11 @@
 //! serde implementation for JsValues
 
+mod bigint;
 mod de;
 mod error;
 mod ser;
 
+pub(crate) use self::bigint::to_bigint;
 pub use self::de::from_jsvalue;
 pub use self::error::Error;
 pub use self::ser::{to_jsvalue, JSVALUE_NEWTYPE_STRUCT, UNDEFINED_UNIT_STRUCT};
@@ -1,3 +1,4 @@
+use crate::jsvalue_serde::bigint::to_bigint;
 use crate::jsvalue_serde::error::{Error, Result};
 use serde::{ser, Serialize};
 use wasm_bindgen::{convert::FromWasmAbi, JsValue};
@@ -32,6 +33,16 @@ macro_rules! def_serializer {
     };
 }
 
+// 128-bit integers don't fit in a javascript number so we serialize them
+// losslessly as bigints
+macro_rules! def_bigint_serializer {
+    ($name:ident, $typ:ty) => {
+        fn $name(self, v: $typ) -> Result<Self::Ok> {
+            Ok(to_bigint(v))
+        }
+    };
+}
+
 impl<'a> ser::Serializer for &'a mut Serializer {
     type Ok = JsValue;
     type Error = Error;
@@ -49,11 +60,13 @@ impl<'a> ser::Serializer for &'a mut Serializer {
     def_serializer!(serialize_i16, i16);
     def_serializer!(serialize_i32, i32);
     def_serializer!(serialize_i64, i64);
+    def_bigint_serializer!(serialize_i128, i128);
 
     def_serializer!(serialize_u8, u8);
     def_serializer!(serialize_u16, u16);
     def_serializer!(serialize_u32, u32);
     def_serializer!(serialize_u64, u64);
+    def_bigint_serializer!(serialize_u128, u128);
 
     def_serializer!(serialize_f32, f32);
     def_serializer!(serialize_f64, f64);
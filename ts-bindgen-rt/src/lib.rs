@@ -5,8 +5,13 @@
 extern crate js_sys;
 extern crate wasm_bindgen;
 
+#[cfg(feature = "futures")]
+mod async_iter;
 pub mod jsvalue_serde;
+pub mod template_literal;
 
+#[cfg(feature = "futures")]
+pub use async_iter::AsyncIterStream;
 pub use jsvalue_serde::{from_jsvalue, to_jsvalue};
 use jsvalue_serde::{Error as SerdeError, JSVALUE_NEWTYPE_STRUCT, UNDEFINED_UNIT_STRUCT};
 use serde::{de, ser};
@@ -116,6 +121,30 @@ where
     serialize_as_jsvalue(serializer, value)
 }
 
+/// Serialize the provided integer into a javascript bigint
+pub fn serialize_as_bigint<S, V>(value: &V, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: ser::Serializer,
+    V: fmt::Display,
+{
+    serialize_jsvalue(&jsvalue_serde::to_bigint(value), serializer)
+}
+
+/// Serialize the provided optional integer into a javascript bigint or null
+pub fn serialize_optional_as_bigint<S, V>(
+    value: &Option<V>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: ser::Serializer,
+    V: fmt::Display,
+{
+    match value {
+        Some(value) => serialize_as_bigint(value, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
 /// Serialize a javascript undefined
 pub fn serialize_undefined<S>(serializer: S) -> Result<S::Ok, S::Error>
 where
@@ -0,0 +1,178 @@
+//! Validation of strings against typescript template literal types,
+//! e.g. `` `#${string}` ``
+
+/// A piece of a template literal type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemplatePart {
+    /// Literal text that must appear as-is
+    Literal(&'static str),
+    /// Exactly one of the provided strings
+    OneOf(&'static [&'static str]),
+    /// Any string, `${string}`
+    String,
+    /// Any string representation of a number, `${number}`
+    Number,
+    /// Any string representation of a bigint, `${bigint}`
+    BigInt,
+}
+
+/// Returns whether `s` matches the template literal type described by `parts`
+pub fn matches(s: &str, parts: &[TemplatePart]) -> bool {
+    let (part, rest) = match parts.split_first() {
+        Some(split) => split,
+        None => return s.is_empty(),
+    };
+
+    match part {
+        TemplatePart::Literal(lit) => s
+            .strip_prefix(lit)
+            .map(|s| matches(s, rest))
+            .unwrap_or(false),
+        TemplatePart::OneOf(options) => options.iter().any(|option| {
+            s.strip_prefix(option)
+                .map(|s| matches(s, rest))
+                .unwrap_or(false)
+        }),
+        TemplatePart::String | TemplatePart::Number | TemplatePart::BigInt => s
+            .char_indices()
+            .map(|(idx, _)| idx)
+            .chain(std::iter::once(s.len()))
+            .any(|idx| {
+                let (hole, s) = s.split_at(idx);
+                is_valid_hole(part, hole) && matches(s, rest)
+            }),
+    }
+}
+
+fn is_valid_hole(part: &TemplatePart, hole: &str) -> bool {
+    match part {
+        TemplatePart::String => true,
+        TemplatePart::Number => is_number_string(hole),
+        TemplatePart::BigInt => {
+            let digits = hole.strip_prefix('-').unwrap_or(hole);
+            !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+        }
+        TemplatePart::Literal(_) | TemplatePart::OneOf(_) => false,
+    }
+}
+
+/// Returns whether javascript parses `s` as a finite number, which is how
+/// typescript decides whether `s` matches `${number}`
+fn is_number_string(s: &str) -> bool {
+    let radix_digits = [("0x", 16), ("0o", 8), ("0b", 2)]
+        .iter()
+        .find_map(|(prefix, radix)| {
+            let digits = s.get(prefix.len()..)?;
+            if s[..prefix.len()].eq_ignore_ascii_case(prefix) {
+                Some((digits, *radix))
+            } else {
+                None
+            }
+        });
+    if let Some((digits, radix)) = radix_digits {
+        return !digits.is_empty() && digits.chars().all(|c| c.is_digit(radix));
+    }
+
+    // rust accepts spellings like "inf" and "NaN" that javascript doesn't
+    // so we only let through the characters of decimal literals
+    let unsigned = s.strip_prefix(|c| c == '+' || c == '-').unwrap_or(s);
+    unsigned.starts_with(|c: char| c.is_ascii_digit() || c == '.')
+        && unsigned
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-'))
+        && s.parse::<f64>().map(f64::is_finite).unwrap_or(false)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_literal() {
+        let parts = [TemplatePart::Literal("abc")];
+        assert!(matches("abc", &parts));
+        assert!(!matches("ab", &parts));
+        assert!(!matches("abcd", &parts));
+        assert!(matches("", &[]));
+        assert!(!matches("a", &[]));
+    }
+
+    #[test]
+    fn test_one_of() {
+        let parts = [
+            TemplatePart::OneOf(&["a", "ab"]),
+            TemplatePart::Literal("c"),
+        ];
+        assert!(matches("ac", &parts));
+        assert!(matches("abc", &parts));
+        assert!(!matches("bc", &parts));
+        assert!(!matches("a", &parts));
+    }
+
+    #[test]
+    fn test_string() {
+        let parts = [TemplatePart::Literal("#"), TemplatePart::String];
+        assert!(matches("#", &parts));
+        assert!(matches("#fff", &parts));
+        assert!(matches("#é", &parts));
+        assert!(!matches("fff", &parts));
+    }
+
+    #[test]
+    fn test_number() {
+        let parts = [TemplatePart::Number, TemplatePart::Literal("px")];
+        for valid in &[
+            "1px", "-1px", "+1px", "1.5px", ".5px", "1.px", "1e3px", "1E-3px", "0x1Fpx", "0o17px",
+            "0b101px",
+        ] {
+            assert!(matches(valid, &parts), "{}", valid);
+        }
+        for invalid in &[
+            "px",
+            " 1px",
+            "1 px",
+            "infpx",
+            "Infinitypx",
+            "NaNpx",
+            "1e999px",
+            "-0x1px",
+            "0xpx",
+            "0b2px",
+            "1_000px",
+            "1e3.5px",
+        ] {
+            assert!(!matches(invalid, &parts), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_bigint() {
+        let parts = [TemplatePart::BigInt, TemplatePart::Literal("n")];
+        assert!(matches("123n", &parts));
+        assert!(matches("-123n", &parts));
+        assert!(!matches("n", &parts));
+        assert!(!matches("-n", &parts));
+        assert!(!matches("1.5n", &parts));
+    }
+
+    #[test]
+    fn test_multiple_holes() {
+        // the first hole must give up characters for the rest to match
+        let parts = [
+            TemplatePart::String,
+            TemplatePart::Literal("-"),
+            TemplatePart::Number,
+            TemplatePart::Literal("-"),
+            TemplatePart::OneOf(&["a", "b"]),
+        ];
+        assert!(matches("x-y-1-a", &parts));
+        assert!(matches("--1-b", &parts));
+        assert!(!matches("x-y-a", &parts));
+        assert!(!matches("x-1-c", &parts));
+
+        let parts = [TemplatePart::Number, TemplatePart::Number];
+        assert!(matches("12", &parts));
+        assert!(matches("1-2", &parts));
+        assert!(!matches("1", &parts));
+    }
+}
@@ -1,10 +1,12 @@
-use clap::Parser;
+use clap::{ArgEnum, Parser};
 use std::fs::write as write_file;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::process::{exit, Command, Stdio};
 use std::str::from_utf8;
-use ts_bindgen_gen::{generate_rust_for_typescript_with_file_processor, StdFs};
+use ts_bindgen_gen::{
+    generate_rust_for_typescript_with_options, BigIntMapping, GenerateOptions, JsImportMode, StdFs,
+};
 use which::which;
 
 /// Generate wasm-bindgen Rust bindings for typescript definitions.
@@ -31,6 +33,41 @@ struct Args {
     /// info.
     #[clap(long)]
     rerun_if_changed: bool,
+
+    /// Bind to javascript globals rather than importing javascript modules. Use this for UMD
+    /// libraries loaded via a script tag. Definitions declaring `export as namespace X` are bound
+    /// within the global `X`.
+    #[clap(long)]
+    global: bool,
+
+    /// Rust type to represent typescript bigints as. js (js_sys::BigInt) holds any bigint while
+    /// the integer types fail to deserialize bigints outside of their range.
+    #[clap(long, arg_enum, default_value = "js")]
+    bigint: BigIntArg,
+
+    /// Represent async iterables as ts_bindgen_rt::AsyncIterStream rather than JsValue. Requires
+    /// the "futures" feature of ts-bindgen-rt.
+    #[clap(long)]
+    async_iter_streams: bool,
+}
+
+#[derive(ArgEnum, Clone, Copy, Debug)]
+enum BigIntArg {
+    Js,
+    I64,
+    U64,
+    I128,
+}
+
+impl From<BigIntArg> for BigIntMapping {
+    fn from(arg: BigIntArg) -> BigIntMapping {
+        match arg {
+            BigIntArg::Js => BigIntMapping::JsBigInt,
+            BigIntArg::I64 => BigIntMapping::I64,
+            BigIntArg::U64 => BigIntMapping::U64,
+            BigIntArg::I128 => BigIntMapping::I128,
+        }
+    }
 }
 
 fn main() {
@@ -49,9 +86,21 @@ fn main() {
         |_: &Path| {}
     };
 
-    let rust_result = generate_rust_for_typescript_with_file_processor(
+    let import_mode = if args.global {
+        JsImportMode::Global
+    } else {
+        JsImportMode::Module
+    };
+
+    let options = GenerateOptions {
+        import_mode,
+        bigint_mapping: args.bigint.into(),
+        async_iter_streams: args.async_iter_streams,
+    };
+    let rust_result = generate_rust_for_typescript_with_options(
         StdFs,
         args.ts_input_file_path,
+        options,
         process_file,
     );
     let rust = match rust_result {